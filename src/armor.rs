@@ -23,6 +23,8 @@ impl Plugin for ArmorPlugin {
         app
             // types
             .register_type::<Armor>()
+            // events
+            .add_event::<ArmorBroken>()
             // systems
             .add_systems(OnEnter(Raid), start_armor_system)
             .add_systems(
@@ -47,6 +49,13 @@ impl Default for Armor {
 
 // Events
 
+/// armor of an entity got depleted by the dealer
+#[derive(Event, Debug, PartialEq)]
+pub struct ArmorBroken {
+    pub entity: Entity,
+    pub dealer: Option<Entity>,
+}
+
 // Systems
 fn start_armor_system(mut _commands: Commands) {
     debug!("starting {}", NAME);
@@ -61,6 +70,7 @@ fn bye_armor_system(mut _commands: Commands) {
 #[allow(clippy::type_complexity)]
 fn damage_received_listener(
     mut armor_damage: EventReader<ArmorDamageReceived>,
+    mut armor_broken: EventWriter<ArmorBroken>,
     mut query: Query<(Entity, &mut Armor)>,
 ) {
     for event in armor_damage.read() {
@@ -70,7 +80,14 @@ fn damage_received_listener(
         );
         for (entity, mut armor) in &mut query {
             if entity == event.entity {
+                let was_intact = armor.0 > 0;
                 armor.0 -= event.damage;
+                if was_intact && armor.0 <= 0 {
+                    armor_broken.send(ArmorBroken {
+                        entity,
+                        dealer: event.dealer,
+                    });
+                }
                 debug!(
                     "event applied to operator {:?}, damage applied: {}",
                     entity, armor.0
@@ -97,6 +114,7 @@ mod tests {
 
         // Add `DamageReceived` event
         app.add_event::<ArmorDamageReceived>();
+        app.add_event::<ArmorBroken>();
 
         // Add event listener
         app.add_systems(Update, damage_received_listener);
@@ -112,6 +130,7 @@ mod tests {
                 entity,
                 damage: 10,
                 dealer: Option::None,
+                weapon: Option::None,
             });
 
         // Run systems
@@ -121,4 +140,37 @@ mod tests {
         assert!(app.world_mut().get::<Armor>(entity).is_some());
         assert_eq!(app.world_mut().get::<Armor>(entity).unwrap().0, 90);
     }
+
+    #[test]
+    fn should_break_armor() {
+        // given
+        let mut app = App::new();
+        app.add_event::<ArmorDamageReceived>();
+        app.add_event::<ArmorBroken>();
+        app.add_systems(Update, damage_received_listener);
+        let entity = app.world_mut().spawn(Armor(10)).id();
+        let dealer = app.world_mut().spawn_empty().id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<ArmorDamageReceived>>()
+            .send(ArmorDamageReceived {
+                entity,
+                damage: 10,
+                dealer: Some(dealer),
+                weapon: Option::None,
+            });
+        app.update();
+
+        // then
+        let armor_broken_events = app.world().resource::<Events<ArmorBroken>>();
+        let mut armor_broken_reader = armor_broken_events.get_cursor();
+        assert_eq!(
+            Some(&ArmorBroken {
+                entity,
+                dealer: Some(dealer),
+            }),
+            armor_broken_reader.read(armor_broken_events).next()
+        );
+    }
 }
@@ -38,8 +38,10 @@ pub struct BackpackSummary(pub u32);
 
 #[derive(Event, Debug, PartialEq)]
 pub struct BackpackSummaryUpdate {
-    old_value: u32,
-    new_value: u32,
+    /// operator owning the backpack
+    pub entity: Entity,
+    pub old_value: u32,
+    pub new_value: u32,
 }
 
 // Systems
@@ -57,6 +59,7 @@ fn on_stowed_money(
             let old_value = summary.0;
             summary.0 += event.amount;
             notification.send(BackpackSummaryUpdate {
+                entity: event.stowing_entity,
                 old_value,
                 new_value: summary.0,
             });
@@ -87,6 +90,7 @@ fn on_stowed_loot(
                         summary.0 += price.0;
                     }
                     notification.send(BackpackSummaryUpdate {
+                        entity: operator.get(),
                         old_value,
                         new_value: summary.0,
                     });
@@ -138,6 +142,7 @@ mod tests {
         let mut notification_reader = notifications.get_cursor();
         let update = notification_reader.read(notifications).next();
         let expected_update = BackpackSummaryUpdate {
+            entity: operator_id,
             old_value: 0,
             new_value: 100,
         };
@@ -188,6 +193,7 @@ mod tests {
         let mut notification_reader = notifications.get_cursor();
         let update = notification_reader.read(notifications).next();
         let expected_update = BackpackSummaryUpdate {
+            entity: operator_id,
             old_value: 0,
             new_value: 100,
         };
@@ -242,6 +248,7 @@ mod tests {
         let mut notification_reader = notifications.get_cursor();
         let update = notification_reader.read(notifications).next();
         let expected_update = BackpackSummaryUpdate {
+            entity: operator_id,
             old_value: 0,
             new_value: 200,
         };
@@ -0,0 +1,388 @@
+use bevy::app::Plugin;
+use bevy::math::bounding::Aabb3d;
+use bevy::utils::HashMap;
+
+use crate::contracts::{
+    contract_squad, ended_contracts, CargoDeliveryState, ContractAccepted, ContractCancelled,
+    ContractCompleted, ContractFailed, ContractGeneralArea, ContractId, ContractSpotlight,
+    ContractState, ContractStateMachine, ContractType, Contracts, FailContract,
+};
+use crate::damage::HurtBox;
+use crate::death::EntityDied;
+use crate::health::Health;
+use crate::interaction::Interactable;
+use crate::raid::Volume;
+use crate::squad::{SquadId, Squads};
+use crate::vehicle::{Vehicle, VehicleEntered};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "cargo delivery";
+const CARGO_HEALTH: i32 = 500;
+/// where the cargo sits on the truck bed
+const CARGO_OFFSET: Vec3 = Vec3::new(0.0, 1.4, -1.2);
+
+// Plugin
+/// contract to drive a cargo truck from its pickup point into the delivery zone
+pub struct CargoDeliveryPlugin;
+
+impl Plugin for CargoDeliveryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_cargo_delivery)
+            .add_systems(
+                Update,
+                (
+                    spawn_cargo_truck,
+                    cargo_picked_up,
+                    deliver_cargo,
+                    cargo_destroyed,
+                    cargo_contract_ended,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_cargo_delivery);
+    }
+}
+
+// Components
+
+/// where the truck of the contract with the same `ContractId` waits
+#[derive(Component, Debug)]
+pub struct CargoPickup;
+
+/// together with a `Volume`, the cargo of the contract with the same `ContractId` is delivered here
+#[derive(Component, Debug)]
+pub struct DeliveryZone;
+
+#[derive(Component, Debug)]
+pub struct CargoTruck;
+
+/// the load of the cargo truck, it can be shot to pieces
+#[derive(Component, Debug)]
+pub struct Cargo;
+
+// Resources
+
+/// contract of all cargo still in one piece, the cargo is gone once destroyed
+#[derive(Resource, Default)]
+struct CargoLoads {
+    map: HashMap<Entity, ContractId>,
+}
+
+// Events
+
+// Systems
+fn start_cargo_delivery(mut commands: Commands) {
+    debug!("starting {}", NAME);
+    commands.init_resource::<CargoLoads>();
+}
+
+fn spawn_cargo_truck(
+    mut commands: Commands,
+    mut accepted: EventReader<ContractAccepted>,
+    contracts: Res<Contracts>,
+    cargo_loads: Option<ResMut<CargoLoads>>,
+    pickups: Query<(Entity, &ContractId, &GlobalTransform), With<CargoPickup>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(mut cargo_loads) = cargo_loads else {
+        return;
+    };
+    for event in accepted.read() {
+        let is_cargo_contract = contracts
+            .map
+            .get(&event.contract_id)
+            .is_some_and(|contract| {
+                matches!(
+                    contract.contract_type,
+                    ContractType::CargoDelivery | ContractType::CargoShipment
+                )
+            });
+        if !is_cargo_contract {
+            continue;
+        }
+        for (pickup, _, transform) in pickups
+            .iter()
+            .filter(|(_, contract_id, _)| **contract_id == event.contract_id)
+        {
+            let position = transform.translation();
+            debug!(
+                "cargo truck of contract {:?} at {}",
+                event.contract_id, position
+            );
+            let mut cargo = None;
+            commands
+                .spawn((
+                    Mesh3d(meshes.add(Cuboid::new(2.2, 1.6, 5.0))),
+                    MeshMaterial3d(materials.add(Color::srgb(0.3, 0.35, 0.25))),
+                    Transform::from_translation(position + Vec3::Y * 0.8)
+                        .with_rotation(transform.rotation()),
+                ))
+                .insert(Name::new("Cargo Truck"))
+                .insert(CargoTruck)
+                .insert(Vehicle::default())
+                .insert(Interactable)
+                .insert(ContractSpotlight)
+                .insert(event.contract_id)
+                .with_children(|parent| {
+                    cargo = Some(
+                        parent
+                            .spawn((
+                                Mesh3d(meshes.add(Cuboid::new(1.6, 1.0, 2.0))),
+                                MeshMaterial3d(materials.add(Color::srgb(0.6, 0.45, 0.2))),
+                                Transform::from_translation(CARGO_OFFSET),
+                            ))
+                            .insert(Name::new("Cargo"))
+                            .insert(Cargo)
+                            .insert(Health(CARGO_HEALTH))
+                            .insert(HurtBox(Aabb3d::new(
+                                Vec3::default(),
+                                Vec3::new(0.8, 0.5, 1.0),
+                            )))
+                            .insert(event.contract_id)
+                            .id(),
+                    );
+                });
+            if let Some(cargo) = cargo {
+                cargo_loads.map.insert(cargo, event.contract_id);
+            }
+            commands
+                .spawn(Transform::from_translation(position))
+                .insert(Name::new("Cargo General Area"))
+                .insert(ContractGeneralArea::default())
+                .insert(event.contract_id);
+            commands.entity(pickup).despawn_recursive();
+        }
+    }
+}
+
+/// the cargo is picked up once a member of the contract squad drives the truck
+fn cargo_picked_up(
+    mut entered: EventReader<VehicleEntered>,
+    trucks: Query<&ContractId, With<CargoTruck>>,
+    operators: Query<&SquadId>,
+    squads: Res<Squads>,
+    mut contracts: ResMut<Contracts>,
+) {
+    for event in entered.read() {
+        let Ok(contract_id) = trucks.get(event.vehicle) else {
+            continue;
+        };
+        let Ok(squad_id) = operators.get(event.driver) else {
+            continue;
+        };
+        if contract_squad(&squads, *contract_id) != Some(squad_id) {
+            continue;
+        }
+        let Some(contract) = contracts.map.get_mut(contract_id) else {
+            continue;
+        };
+        if let ContractState::CargoDelivery(mut state @ CargoDeliveryState::Started) =
+            contract.contract_state
+        {
+            debug!("cargo of contract {:?} picked up", contract_id);
+            contract.contract_state = state.next();
+        }
+    }
+}
+
+/// pays out when the truck arrives in the delivery zone with its cargo still in one piece
+fn deliver_cargo(
+    trucks: Query<(&ContractId, &GlobalTransform, &Children), With<CargoTruck>>,
+    zones: Query<(&ContractId, &Volume, &GlobalTransform), With<DeliveryZone>>,
+    cargo_loads: Option<Res<CargoLoads>>,
+    mut contracts: ResMut<Contracts>,
+    mut completed: EventWriter<ContractCompleted>,
+) {
+    let Some(cargo_loads) = cargo_loads else {
+        return;
+    };
+    for (contract_id, transform, children) in trucks.iter() {
+        let has_cargo = children
+            .iter()
+            .any(|child| cargo_loads.map.contains_key(child));
+        if !has_cargo {
+            continue;
+        }
+        let position = transform.translation();
+        let in_zone = zones.iter().any(|(zone_contract_id, volume, zone)| {
+            zone_contract_id == contract_id && volume.contains(zone.translation(), position)
+        });
+        if !in_zone {
+            continue;
+        }
+        let Some(contract) = contracts.map.get_mut(contract_id) else {
+            continue;
+        };
+        if let ContractState::CargoDelivery(mut state @ CargoDeliveryState::CargoPickedUp) =
+            contract.contract_state
+        {
+            debug!("cargo of contract {:?} delivered", contract_id);
+            contract.contract_state = state.next();
+            completed.send(ContractCompleted {
+                contract_id: *contract_id,
+            });
+        }
+    }
+}
+
+fn cargo_destroyed(
+    mut died: EventReader<EntityDied>,
+    cargo_loads: Option<ResMut<CargoLoads>>,
+    mut fail: EventWriter<FailContract>,
+) {
+    let Some(mut cargo_loads) = cargo_loads else {
+        return;
+    };
+    for event in died.read() {
+        if let Some(contract_id) = cargo_loads.map.remove(&event.death) {
+            debug!("cargo of contract {:?} destroyed", contract_id);
+            fail.send(FailContract { contract_id });
+        }
+    }
+}
+
+/// the truck of a failed or cancelled contract is just a vehicle from then on
+fn cargo_contract_ended(
+    mut commands: Commands,
+    mut failed: EventReader<ContractFailed>,
+    mut cancelled: EventReader<ContractCancelled>,
+    cargo_loads: Option<ResMut<CargoLoads>>,
+    trucks: Query<(Entity, &ContractId), With<CargoTruck>>,
+) {
+    let Some(mut cargo_loads) = cargo_loads else {
+        return;
+    };
+    let ended = ended_contracts(&mut failed, &mut cancelled);
+    if ended.is_empty() {
+        return;
+    }
+    cargo_loads
+        .map
+        .retain(|_, contract_id| !ended.contains(contract_id));
+    for (truck, contract_id) in trucks.iter() {
+        if ended.contains(contract_id) {
+            debug!(
+                "cargo truck of contract {:?} is no objective anymore",
+                contract_id
+            );
+            commands.entity(truck).remove::<ContractSpotlight>();
+        }
+    }
+}
+
+fn bye_cargo_delivery(mut commands: Commands, trucks: Query<Entity, With<CargoTruck>>) {
+    debug!("stopping {}", NAME);
+    commands.remove_resource::<CargoLoads>();
+    for truck in trucks.iter() {
+        commands.entity(truck).despawn_recursive();
+    }
+}
+
+// helper functions
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::contracts::insert_held_contract;
+    use crate::death::DeathKind;
+
+    fn cargo_app(contract_id: ContractId, state: CargoDeliveryState) -> App {
+        let mut app = App::new();
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::CargoDelivery,
+            ContractState::CargoDelivery(state),
+        );
+        app.init_resource::<CargoLoads>();
+        app
+    }
+
+    #[test]
+    fn should_deliver_cargo_in_delivery_zone() {
+        // given
+        let contract_id = ContractId(51);
+        let mut app = cargo_app(contract_id, CargoDeliveryState::CargoPickedUp);
+        app.add_event::<ContractCompleted>();
+        app.add_systems(Update, deliver_cargo);
+        let truck = app
+            .world_mut()
+            .spawn((
+                CargoTruck,
+                contract_id,
+                GlobalTransform::from_xyz(20.0, 0.8, 0.0),
+            ))
+            .id();
+        let cargo = app.world_mut().spawn(Cargo).set_parent(truck).id();
+        app.world_mut()
+            .resource_mut::<CargoLoads>()
+            .map
+            .insert(cargo, contract_id);
+        app.world_mut().spawn((
+            DeliveryZone,
+            contract_id,
+            Volume(Aabb3d::new(Vec3::ZERO, Vec3::new(5.0, 2.0, 5.0))),
+            GlobalTransform::from_xyz(22.0, 0.0, 1.0),
+        ));
+
+        // when
+        app.update();
+
+        // then
+        assert_eq!(
+            ContractState::CargoDelivery(CargoDeliveryState::Delivered),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        let completed_events = app.world().resource::<Events<ContractCompleted>>();
+        let mut completed_reader = completed_events.get_cursor();
+        assert_eq!(
+            vec![&ContractCompleted { contract_id }],
+            completed_reader.read(completed_events).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_fail_contract_when_cargo_destroyed() {
+        // given
+        let contract_id = ContractId(52);
+        let mut app = cargo_app(contract_id, CargoDeliveryState::CargoPickedUp);
+        app.add_event::<EntityDied>();
+        app.add_event::<FailContract>();
+        app.add_systems(Update, cargo_destroyed);
+        let cargo = app.world_mut().spawn(Cargo).id();
+        app.world_mut()
+            .resource_mut::<CargoLoads>()
+            .map
+            .insert(cargo, contract_id);
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<EntityDied>>()
+            .send(EntityDied {
+                death: cargo,
+                killer: None,
+                killer_squad: None,
+                death_squad: None,
+                death_kind: DeathKind::Other,
+                death_name: None,
+                weapon: None,
+                assists: vec![],
+            });
+        app.update();
+
+        // then
+        let fail_events = app.world().resource::<Events<FailContract>>();
+        let mut fail_reader = fail_events.get_cursor();
+        assert_eq!(
+            vec![&FailContract { contract_id }],
+            fail_reader.read(fail_events).collect::<Vec<_>>()
+        );
+    }
+}
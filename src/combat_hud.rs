@@ -0,0 +1,314 @@
+use bevy::app::Plugin;
+
+use crate::armor::ArmorBroken;
+use crate::damage::{ArmorDamageReceived, HealthDamageReceived};
+use crate::death::{EntityDie, EntityDied};
+use crate::first_person_controller::PlayerControlled;
+use crate::squad::SquadId;
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "combat hud";
+/// seconds a kill stays visible in the kill feed
+const KILL_FEED_ENTRY_SECS: f32 = 5.0;
+/// max amount of kills shown at once, oldest ones are dropped first
+const KILL_FEED_MAX_ENTRIES: usize = 5;
+/// seconds a hit marker stays visible in the center of the screen
+const HIT_MARKER_SECS: f32 = 0.25;
+const HIT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const ARMOR_BREAK_COLOR: Color = Color::srgb(0.2, 0.6, 1.0);
+const KILL_COLOR: Color = Color::srgb(0.9, 0.1, 0.1);
+
+// Plugin
+/// in raid overlay for combat related feedback: kill feed and hit markers
+pub struct CombatHudPlugin;
+
+impl Plugin for CombatHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_combat_hud)
+            .add_systems(
+                Update,
+                (
+                    entity_died_received,
+                    update_kill_feed,
+                    hit_received,
+                    update_hit_markers,
+                )
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_combat_hud);
+    }
+}
+
+// Components
+
+#[derive(Component)]
+struct KillFeedUI;
+
+#[derive(Component)]
+struct KillFeedEntry(Timer);
+
+/// always visible screen center the hit markers are attached to, the crosshair itself is
+/// hidden unless toggled
+#[derive(Component)]
+struct HitMarkerUI;
+
+#[derive(Component)]
+struct HitMarker(Timer);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum HitMarkerKind {
+    Hit,
+    ArmorBreak,
+    Kill,
+}
+
+impl HitMarkerKind {
+    fn color(&self) -> Color {
+        match self {
+            HitMarkerKind::Hit => HIT_COLOR,
+            HitMarkerKind::ArmorBreak => ARMOR_BREAK_COLOR,
+            HitMarkerKind::Kill => KILL_COLOR,
+        }
+    }
+
+    fn font_size(&self) -> f32 {
+        match self {
+            HitMarkerKind::Hit => 14.0,
+            HitMarkerKind::ArmorBreak => 18.0,
+            HitMarkerKind::Kill => 22.0,
+        }
+    }
+}
+
+// Resources
+
+// Events
+
+// Systems
+fn start_combat_hud(mut commands: Commands) {
+    debug!("starting {}", NAME);
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            right: Val::Px(10.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::FlexEnd,
+            ..default()
+        })
+        .insert(Name::new("Kill Feed"))
+        .insert(KillFeedUI);
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(50.0),
+            top: Val::Percent(50.0),
+            ..default()
+        })
+        .insert(Name::new("Hit Markers"))
+        .insert(HitMarkerUI);
+}
+
+/// adds deaths involving the squad of the player to the kill feed
+fn entity_died_received(
+    mut commands: Commands,
+    mut entity_died: EventReader<EntityDied>,
+    player: Query<&SquadId, With<PlayerControlled>>,
+    names: Query<&Name>,
+    kill_feed: Query<(Entity, Option<&Children>), With<KillFeedUI>>,
+) {
+    let Ok(player_squad) = player.get_single() else {
+        return;
+    };
+    let Ok((kill_feed, entries)) = kill_feed.get_single() else {
+        return;
+    };
+    let mut entry_count = entries.map(|e| e.len()).unwrap_or_default();
+    for event in entity_died.read() {
+        let squad_involved = event.killer_squad.as_ref() == Some(player_squad)
+            || event.death_squad.as_ref() == Some(player_squad);
+        if !squad_involved {
+            continue;
+        }
+        let text = kill_feed_text(
+            event.killer.and_then(|k| names.get(k).ok()),
+            event.weapon.and_then(|w| names.get(w).ok()),
+            event.death_name.as_ref(),
+        );
+        debug!("kill feed: {}", text);
+        let color = if event.killer_squad.as_ref() == Some(player_squad) {
+            HIT_COLOR
+        } else {
+            KILL_COLOR
+        };
+        commands
+            .spawn(Text::new(text))
+            .insert(TextFont {
+                font_size: 12.0,
+                ..default()
+            })
+            .insert(TextColor(color))
+            .insert(KillFeedEntry(Timer::from_seconds(
+                KILL_FEED_ENTRY_SECS,
+                TimerMode::Once,
+            )))
+            .set_parent(kill_feed);
+        entry_count += 1;
+    }
+    if let Some(entries) = entries {
+        let overflow = entry_count.saturating_sub(KILL_FEED_MAX_ENTRIES);
+        for entry in entries.iter().take(overflow) {
+            commands.entity(*entry).despawn_recursive();
+        }
+    }
+}
+
+fn update_kill_feed(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut entries: Query<(Entity, &mut KillFeedEntry)>,
+) {
+    for (entity, mut entry) in entries.iter_mut() {
+        if entry.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// shows a hit marker in the center of the screen for damage dealt by the player. the strongest marker of
+/// the frame wins: kill over armor break over a plain hit.
+#[allow(clippy::too_many_arguments)]
+fn hit_received(
+    mut commands: Commands,
+    mut health_damage: EventReader<HealthDamageReceived>,
+    mut armor_damage: EventReader<ArmorDamageReceived>,
+    mut armor_broken: EventReader<ArmorBroken>,
+    mut dying: EventReader<EntityDie>,
+    player: Query<Entity, With<PlayerControlled>>,
+    hit_marker_ui: Query<Entity, With<HitMarkerUI>>,
+    markers: Query<Entity, With<HitMarker>>,
+) {
+    let Ok(player) = player.get_single() else {
+        return;
+    };
+    let by_player = |dealer: Option<Entity>| dealer == Some(player);
+    // count instead of any, so the readers are always drained
+    let hits = health_damage.read().filter(|e| by_player(e.dealer)).count()
+        + armor_damage.read().filter(|e| by_player(e.dealer)).count();
+    let armor_breaks = armor_broken.read().filter(|e| by_player(e.dealer)).count();
+    let kills = dying.read().filter(|e| by_player(e.killer)).count();
+
+    let Some(kind) = hit_marker_kind(hits > 0, armor_breaks > 0, kills > 0) else {
+        return;
+    };
+    let Ok(hit_marker_ui) = hit_marker_ui.get_single() else {
+        return;
+    };
+    debug!("hit marker: {:?}", kind);
+    for marker in markers.iter() {
+        commands.entity(marker).despawn_recursive();
+    }
+    commands
+        .spawn(Text::new("X"))
+        .insert(TextFont {
+            font_size: kind.font_size(),
+            ..default()
+        })
+        .insert(TextColor(kind.color()))
+        .insert(Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(-kind.font_size() / 2.0),
+            top: Val::Px(-kind.font_size() / 2.0),
+            ..default()
+        })
+        .insert(HitMarker(Timer::from_seconds(
+            HIT_MARKER_SECS,
+            TimerMode::Once,
+        )))
+        .set_parent(hit_marker_ui);
+}
+
+fn update_hit_markers(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut markers: Query<(Entity, &mut HitMarker)>,
+) {
+    for (entity, mut marker) in markers.iter_mut() {
+        if marker.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn bye_combat_hud(
+    mut commands: Commands,
+    hud: Query<Entity, Or<(With<KillFeedUI>, With<HitMarkerUI>)>>,
+) {
+    debug!("stopping {}", NAME);
+    for entity in hud.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// helper functions
+
+fn hit_marker_kind(hit: bool, armor_break: bool, kill: bool) -> Option<HitMarkerKind> {
+    [
+        (hit, HitMarkerKind::Hit),
+        (armor_break, HitMarkerKind::ArmorBreak),
+        (kill, HitMarkerKind::Kill),
+    ]
+    .into_iter()
+    .filter(|(happened, _)| *happened)
+    .map(|(_, kind)| kind)
+    .max()
+}
+
+fn kill_feed_text(killer: Option<&Name>, weapon: Option<&Name>, victim: Option<&Name>) -> String {
+    let killer = killer.map(|n| n.as_str()).unwrap_or("???");
+    let victim = victim.map(|n| n.as_str()).unwrap_or("???");
+    match weapon {
+        Some(weapon) => format!("{} [{}] {}", killer, weapon.as_str(), victim),
+        None => format!("{} {}", killer, victim),
+    }
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn should_prefer_strongest_hit_marker() {
+        assert_eq!(None, hit_marker_kind(false, false, false));
+        assert_eq!(
+            Some(HitMarkerKind::Hit),
+            hit_marker_kind(true, false, false)
+        );
+        assert_eq!(
+            Some(HitMarkerKind::ArmorBreak),
+            hit_marker_kind(true, true, false)
+        );
+        assert_eq!(Some(HitMarkerKind::Kill), hit_marker_kind(true, true, true));
+    }
+
+    #[test]
+    fn should_format_kill_feed_text() {
+        // given
+        let killer = Name::new("Operator");
+        let weapon = Name::new("Weapon");
+        let victim = Name::new("Zombie");
+
+        // when
+        let with_weapon = kill_feed_text(Some(&killer), Some(&weapon), Some(&victim));
+        let without_killer = kill_feed_text(None, None, Some(&victim));
+
+        // then
+        assert_eq!("Operator [Weapon] Zombie", with_weapon);
+        assert_eq!("??? Zombie", without_killer);
+    }
+}
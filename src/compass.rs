@@ -2,6 +2,7 @@ use bevy::app::Plugin;
 
 use crate::exfil::Operator;
 use crate::first_person_controller::PlayerControlled;
+use crate::gas::GasCircle;
 use crate::raid::RaidState;
 use crate::AppState;
 use crate::AppState::Raid;
@@ -23,6 +24,7 @@ impl Plugin for CompassPlugin {
                     update_compass_ui,
                     update_direction_label,
                     update_heading_label,
+                    update_gas_label,
                 )
                     .chain()
                     .run_if(in_state(AppState::Raid))
@@ -46,6 +48,9 @@ struct HeadingLabel;
 #[derive(Component)]
 struct DirectionLabel;
 
+#[derive(Component)]
+struct GasLabel;
+
 #[derive(Component, Default)]
 pub struct Compass {
     pub heading: i32,
@@ -144,6 +149,18 @@ fn start_compass_ui(mut commands: Commands) {
                 //.with_text_justify(JustifyText::Center),
                 .insert(TextColor(Color::srgb(0.9, 0.9, 0.9)))
                 .insert(HeadingLabel);
+            parent
+                .spawn(Text::new(String::new()))
+                .insert(TextFont {
+                    font_size: 10.0,
+                    ..default()
+                })
+                .insert(Node {
+                    justify_self: JustifySelf::Center,
+                    ..default()
+                })
+                .insert(TextColor(Color::srgb(0.9, 0.6, 0.0)))
+                .insert(GasLabel);
         })
         .id();
 
@@ -200,6 +217,27 @@ fn update_heading_label(
     *writer.text(ui_query.single(), 0) = format!("{}", compass.heading);
 }
 
+/// distance to the edge of the gas circle, empty as long as there is no gas
+fn update_gas_label(
+    operator_query: Query<&GlobalTransform, (With<Operator>, With<PlayerControlled>)>,
+    gas: Option<Res<GasCircle>>,
+    mut writer: TextUiWriter,
+    ui_query: Query<Entity, With<GasLabel>>,
+) {
+    let label = match (gas, operator_query.get_single()) {
+        (Some(gas), Ok(operator)) => {
+            let distance = gas.distance_to_edge(operator.translation());
+            if distance > 0.0 {
+                format!("GAS {:.0}m", distance)
+            } else {
+                format!("SAFE {:.0}m", -distance)
+            }
+        }
+        _ => String::new(),
+    };
+    *writer.text(ui_query.single(), 0) = label;
+}
+
 fn bye_compass_system(mut commands: Commands, compass_ui: Query<Entity, With<CompassUI>>) {
     debug!("stopping {}", NAME);
     let ui = compass_ui.single();
@@ -0,0 +1,238 @@
+use bevy::app::Plugin;
+
+use crate::contracts::{CancelContract, ContractDeadlines, ContractId, Contracts};
+use crate::exfil::Operator;
+use crate::first_person_controller::PlayerControlled;
+use crate::inventory_ui::{cleanup_cursor_crosshair, startup_cursor_crosshair};
+use crate::raid::RaidState;
+use crate::squad::{SquadId, Squads};
+use crate::AppState;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "contract menu";
+const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
+const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
+const PRESSED_BUTTON: Color = Color::srgb(0.75, 0.35, 0.35);
+
+// Plugin
+/// in raid overview of the current contract of the player's squad, the contract can be abandoned
+/// from here
+pub struct ContractMenuPlugin;
+
+impl Plugin for ContractMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (toggle_contract_menu).run_if(in_state(AppState::Raid)),
+        )
+        .add_systems(
+            OnEnter(RaidState::AccessMissions),
+            (startup_cursor_crosshair, start_contract_menu_ui)
+                .chain()
+                .run_if(in_state(AppState::Raid)),
+        )
+        .add_systems(
+            Update,
+            (contract_menu_buttons)
+                .run_if(in_state(AppState::Raid))
+                .run_if(in_state(RaidState::AccessMissions)),
+        )
+        .add_systems(
+            OnExit(RaidState::AccessMissions),
+            (cleanup_cursor_crosshair, bye_contract_menu_ui).chain(),
+        );
+    }
+}
+
+// Components
+
+#[derive(Component)]
+struct ContractMenuUI;
+
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+enum ContractMenuButton {
+    Abandon(ContractId),
+    Close,
+}
+
+// Resources
+
+// Events
+
+// Systems
+
+/// toggles sub state for AccessMissions
+fn toggle_contract_menu(
+    key_input: Res<ButtonInput<KeyCode>>,
+    raid_state: Res<State<RaidState>>,
+    mut next_raid_state: ResMut<NextState<RaidState>>,
+) {
+    if key_input.just_pressed(KeyCode::F7) {
+        match raid_state.get() {
+            RaidState::Raid => next_raid_state.set(RaidState::AccessMissions),
+            RaidState::AccessMissions => next_raid_state.set(RaidState::Raid),
+            _ => (),
+        }
+    }
+}
+
+fn start_contract_menu_ui(
+    mut commands: Commands,
+    player: Query<&SquadId, (With<PlayerControlled>, With<Operator>)>,
+    squads: Res<Squads>,
+    contracts: Option<Res<Contracts>>,
+    deadlines: Option<Res<ContractDeadlines>>,
+) {
+    debug!("starting {} ui", NAME);
+    let current_contract = player
+        .get_single()
+        .ok()
+        .and_then(|squad_id| squads.map.get(squad_id))
+        .and_then(|squad| squad.current_contract);
+    let text = match current_contract {
+        Some(contract_id) => {
+            let contract = contracts
+                .as_ref()
+                .and_then(|contracts| contracts.map.get(&contract_id));
+            let time_left = deadlines
+                .as_ref()
+                .and_then(|deadlines| deadlines.map.get(&contract_id))
+                .map(|deadline| deadline.remaining_secs());
+            match contract {
+                Some(contract) => contract_text(
+                    &format!("{:?}", contract.contract_type),
+                    &format!("{:?}", contract.contract_state),
+                    time_left,
+                ),
+                None => String::from("NO CONTRACT"),
+            }
+        }
+        None => String::from("NO CONTRACT"),
+    };
+    commands
+        .spawn(Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            padding: UiRect {
+                top: Val::Percent(10.),
+                ..default()
+            },
+            justify_self: JustifySelf::Center,
+            ..default()
+        })
+        .insert(BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)))
+        .insert(ContractMenuUI)
+        .insert(Name::new("Contract Menu Layout"))
+        .with_children(|builder| {
+            builder
+                .spawn(Text::new(text))
+                .insert(TextFont {
+                    font_size: 30.0,
+                    ..default()
+                })
+                .insert(TextColor(Color::srgb(0.9, 0.9, 0.9)));
+            if let Some(contract_id) = current_contract {
+                spawn_menu_button(builder, ContractMenuButton::Abandon(contract_id));
+            }
+            spawn_menu_button(builder, ContractMenuButton::Close);
+        });
+}
+
+#[allow(clippy::type_complexity)]
+fn contract_menu_buttons(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &ContractMenuButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut cancel: EventWriter<CancelContract>,
+    mut next_raid_state: ResMut<NextState<RaidState>>,
+) {
+    for (interaction, mut color, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                if let ContractMenuButton::Abandon(contract_id) = *button {
+                    debug!("abandoning contract {:?}", contract_id);
+                    cancel.send(CancelContract { contract_id });
+                }
+                next_raid_state.set(RaidState::Raid);
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
+
+fn bye_contract_menu_ui(mut commands: Commands, ui: Query<Entity, With<ContractMenuUI>>) {
+    debug!("stopping {} ui", NAME);
+    for entity in ui.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// helper functions
+
+fn contract_text(contract_type: &str, contract_state: &str, time_left: Option<f32>) -> String {
+    match time_left {
+        Some(secs) => format!(
+            "{}\n{}\n{:.0}s LEFT",
+            contract_type,
+            contract_state,
+            secs.ceil()
+        ),
+        None => format!("{}\n{}", contract_type, contract_state),
+    }
+}
+
+fn button_label(button: ContractMenuButton) -> String {
+    match button {
+        ContractMenuButton::Abandon(_) => String::from("ABANDON"),
+        ContractMenuButton::Close => String::from("CLOSE"),
+    }
+}
+
+fn spawn_menu_button(builder: &mut ChildBuilder, button: ContractMenuButton) {
+    builder
+        .spawn(Button)
+        .insert(Node {
+            width: Val::Px(200.),
+            height: Val::Px(60.),
+            margin: UiRect::all(Val::Px(5.)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .insert(BackgroundColor(NORMAL_BUTTON))
+        .insert(button)
+        .with_children(|parent| {
+            parent
+                .spawn(Text::new(button_label(button)))
+                .insert(TextFont {
+                    font_size: 30.0,
+                    ..default()
+                })
+                .insert(TextColor(Color::srgb(0.9, 0.9, 0.9)));
+        });
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn should_show_time_left_only_with_time_limit() {
+        // then
+        assert_eq!(
+            "HuntSquad\nStarted\n13s LEFT",
+            contract_text("HuntSquad", "Started", Some(12.2))
+        );
+        assert_eq!(
+            "SecureIntel\nStarted",
+            contract_text("SecureIntel", "Started", None)
+        );
+    }
+}
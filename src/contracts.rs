@@ -1,5 +1,6 @@
 use bevy::app::Plugin;
 
+use crate::death::{DeathKind, EntityDied};
 use crate::exfil::Operator;
 use crate::interaction::InventoryInteracted;
 use crate::squad::{SquadId, Squads};
@@ -24,6 +25,11 @@ impl Plugin for ContractsPlugin {
             .add_event::<SecureSuppliesUpdated>()
             .add_event::<SecureSuppliesFinished>()
             .add_event::<FinishedContract>()
+            .add_event::<ContractCompleted>()
+            .add_event::<FailContract>()
+            .add_event::<ContractFailed>()
+            .add_event::<CancelContract>()
+            .add_event::<ContractCancelled>()
             .add_systems(OnEnter(Raid), start_contract_system)
             .add_systems(
                 Update,
@@ -33,6 +39,12 @@ impl Plugin for ContractsPlugin {
                     update_secure_supplies,
                     secure_supplies_interacted,
                     finished_secure_supply,
+                    complete_contract,
+                    expire_contracts,
+                    squad_wiped_out,
+                    fail_contract,
+                    cancel_contract,
+                    clear_ended_contract_areas,
                 )
                     .run_if(in_state(AppState::Raid)),
             )
@@ -81,6 +93,12 @@ impl Default for ContractGeneralArea {
     }
 }
 
+impl ContractGeneralArea {
+    pub fn new(radius: f32) -> Self {
+        ContractGeneralArea { radius }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Component, Clone, Reflect, InspectorOptions, Debug, PartialEq)]
 #[reflect(Component, InspectorOptions)]
@@ -108,6 +126,10 @@ impl Default for ContractPayout {
     }
 }
 
+/// seconds the squad has to finish the contract of this phone once accepted, no limit without it
+#[derive(Component, Debug)]
+pub struct ContractTimeLimit(pub f32);
+
 // contract statemachines
 
 #[allow(dead_code)]
@@ -117,10 +139,27 @@ pub enum ContractState {
     #[default]
     Started,
     SecureSupplies(SecureSuppliesState),
+    SecureIntel(SecureIntelState),
+    EliminateHVT(EliminateHVTState),
+    RescueHostage(RescueHostageState),
+    DestroySupplies(DestroySuppliesState),
+    RaidWeaponStash(RaidWeaponStashState),
+    SignalIntelligence(SignalIntelligenceState),
+    HuntSquad(HuntSquadState),
+    CargoDelivery(CargoDeliveryState),
+    SecureNuclearMaterials(SecureNuclearMaterialsState),
     Cancelled,
+    Failed,
     Finished,
 }
 
+impl ContractState {
+    /// nothing changes anymore once a contract is over
+    pub fn is_over(&self) -> bool {
+        matches!(self, Self::Cancelled | Self::Failed | Self::Finished)
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Default, Copy, Clone, Reflect, InspectorOptions, Debug, PartialEq)]
 #[reflect(InspectorOptions)]
@@ -132,12 +171,107 @@ pub enum SecureSuppliesState {
     ThirdSupplySecured,
 }
 
-// TODO: remaining statemachines
+#[allow(dead_code)]
+#[derive(Default, Copy, Clone, Reflect, InspectorOptions, Debug, PartialEq)]
+#[reflect(InspectorOptions)]
+pub enum SecureIntelState {
+    #[default]
+    Started,
+    /// the intel is in the backpack of a squad member
+    IntelSecured,
+}
+
+#[allow(dead_code)]
+#[derive(Default, Copy, Clone, Reflect, InspectorOptions, Debug, PartialEq)]
+#[reflect(InspectorOptions)]
+pub enum EliminateHVTState {
+    #[default]
+    Started,
+    /// a squad member got close enough to the HVT to spot it
+    Located,
+    Eliminated,
+}
+
+#[allow(dead_code)]
+#[derive(Default, Copy, Clone, Reflect, InspectorOptions, Debug, PartialEq)]
+#[reflect(InspectorOptions)]
+pub enum RescueHostageState {
+    #[default]
+    Started,
+    /// the hostage follows the operator that freed it
+    Freed,
+    /// the hostage made it alive into a rescue zone or exfil
+    Rescued,
+}
+
+#[allow(dead_code)]
+#[derive(Default, Copy, Clone, Reflect, InspectorOptions, Debug, PartialEq)]
+#[reflect(InspectorOptions)]
+pub enum DestroySuppliesState {
+    #[default]
+    Started,
+    /// number of supply crates blown up so far
+    CratesDestroyed(u32),
+}
+
+#[allow(dead_code)]
+#[derive(Default, Copy, Clone, Reflect, InspectorOptions, Debug, PartialEq)]
+#[reflect(InspectorOptions)]
+pub enum RaidWeaponStashState {
+    #[default]
+    Started,
+    /// the keypad of the stash took the code
+    StashUnlocked,
+}
+
+#[allow(dead_code)]
+#[derive(Default, Copy, Clone, Reflect, InspectorOptions, Debug, PartialEq)]
+#[reflect(InspectorOptions)]
+pub enum SignalIntelligenceState {
+    #[default]
+    Started,
+    /// number of uplinks activated so far
+    UplinksActivated(u32),
+}
+
+#[allow(dead_code)]
+#[derive(Default, Copy, Clone, Reflect, InspectorOptions, Debug, PartialEq)]
+#[reflect(InspectorOptions)]
+pub enum HuntSquadState {
+    #[default]
+    Started,
+    /// every operator of the target squad is dead
+    SquadEliminated,
+}
+
+/// cargo shipments are delivered the same way
+#[allow(dead_code)]
+#[derive(Default, Copy, Clone, Reflect, InspectorOptions, Debug, PartialEq)]
+#[reflect(InspectorOptions)]
+pub enum CargoDeliveryState {
+    #[default]
+    Started,
+    /// a squad member is driving the cargo truck
+    CargoPickedUp,
+    Delivered,
+}
+
+#[allow(dead_code)]
+#[derive(Default, Copy, Clone, Reflect, InspectorOptions, Debug, PartialEq)]
+#[reflect(InspectorOptions)]
+pub enum SecureNuclearMaterialsState {
+    #[default]
+    Started,
+    /// a squad member carries the nuclear material
+    MaterialsRecovered,
+    /// dropped off or exfilled
+    MaterialsSecured,
+}
 
 // state machine
 
 #[allow(dead_code)]
-trait ContractStateMachine {
+pub trait ContractStateMachine {
     fn next(&mut self) -> ContractState;
 }
 
@@ -152,6 +286,98 @@ impl ContractStateMachine for SecureSuppliesState {
     }
 }
 
+impl ContractStateMachine for SecureIntelState {
+    fn next(&mut self) -> ContractState {
+        match self {
+            Self::Started => ContractState::SecureIntel(Self::IntelSecured),
+            Self::IntelSecured => ContractState::Finished,
+        }
+    }
+}
+
+impl ContractStateMachine for EliminateHVTState {
+    fn next(&mut self) -> ContractState {
+        match self {
+            Self::Started => ContractState::EliminateHVT(Self::Located),
+            Self::Located => ContractState::EliminateHVT(Self::Eliminated),
+            Self::Eliminated => ContractState::Finished,
+        }
+    }
+}
+
+impl ContractStateMachine for RescueHostageState {
+    fn next(&mut self) -> ContractState {
+        match self {
+            Self::Started => ContractState::RescueHostage(Self::Freed),
+            Self::Freed => ContractState::RescueHostage(Self::Rescued),
+            Self::Rescued => ContractState::Finished,
+        }
+    }
+}
+
+/// finishing is up to the contract, it knows how many crates there are
+impl ContractStateMachine for DestroySuppliesState {
+    fn next(&mut self) -> ContractState {
+        match self {
+            Self::Started => ContractState::DestroySupplies(Self::CratesDestroyed(1)),
+            Self::CratesDestroyed(n) => {
+                ContractState::DestroySupplies(Self::CratesDestroyed(*n + 1))
+            }
+        }
+    }
+}
+
+impl ContractStateMachine for RaidWeaponStashState {
+    fn next(&mut self) -> ContractState {
+        match self {
+            Self::Started => ContractState::RaidWeaponStash(Self::StashUnlocked),
+            Self::StashUnlocked => ContractState::Finished,
+        }
+    }
+}
+
+impl ContractStateMachine for SignalIntelligenceState {
+    fn next(&mut self) -> ContractState {
+        match self {
+            Self::Started => ContractState::SignalIntelligence(Self::UplinksActivated(1)),
+            Self::UplinksActivated(n) => {
+                ContractState::SignalIntelligence(Self::UplinksActivated(*n + 1))
+            }
+        }
+    }
+}
+
+impl ContractStateMachine for HuntSquadState {
+    fn next(&mut self) -> ContractState {
+        match self {
+            Self::Started => ContractState::HuntSquad(Self::SquadEliminated),
+            Self::SquadEliminated => ContractState::Finished,
+        }
+    }
+}
+
+impl ContractStateMachine for CargoDeliveryState {
+    fn next(&mut self) -> ContractState {
+        match self {
+            Self::Started => ContractState::CargoDelivery(Self::CargoPickedUp),
+            Self::CargoPickedUp => ContractState::CargoDelivery(Self::Delivered),
+            Self::Delivered => ContractState::Finished,
+        }
+    }
+}
+
+impl ContractStateMachine for SecureNuclearMaterialsState {
+    fn next(&mut self) -> ContractState {
+        match self {
+            Self::Started => ContractState::SecureNuclearMaterials(Self::MaterialsRecovered),
+            Self::MaterialsRecovered => {
+                ContractState::SecureNuclearMaterials(Self::MaterialsSecured)
+            }
+            Self::MaterialsSecured => ContractState::Finished,
+        }
+    }
+}
+
 // Resources
 #[allow(dead_code)]
 #[derive(Reflect, InspectorOptions, Debug, PartialEq)]
@@ -168,6 +394,12 @@ pub struct Contracts {
     pub map: HashMap<ContractId, Contract>,
 }
 
+/// time left for contracts with a time limit, only runs down while a squad holds the contract
+#[derive(Resource, Default)]
+pub struct ContractDeadlines {
+    pub map: HashMap<ContractId, Timer>,
+}
+
 // Events
 
 #[derive(Event, Debug, PartialEq)]
@@ -197,25 +429,62 @@ pub struct FinishedContract {
     pub contract_id: ContractId,
 }
 
+/// all objectives of a contract are done, pays out the squad that holds the contract
+#[derive(Event, Debug, PartialEq)]
+pub struct ContractCompleted {
+    pub contract_id: ContractId,
+}
+
+/// an objective of the contract can't be done anymore
+#[derive(Event, Debug, PartialEq)]
+pub struct FailContract {
+    pub contract_id: ContractId,
+}
+
+#[derive(Event, Debug, PartialEq)]
+pub struct ContractFailed {
+    pub contract_id: ContractId,
+}
+
+/// the squad holding the contract abandons it
+#[derive(Event, Debug, PartialEq)]
+pub struct CancelContract {
+    pub contract_id: ContractId,
+}
+
+#[derive(Event, Debug, PartialEq)]
+pub struct ContractCancelled {
+    pub contract_id: ContractId,
+}
+
 // Systems
 fn start_contract_system(mut commands: Commands) {
     debug!("starting {}", NAME);
     commands.insert_resource(Contracts::default());
+    commands.insert_resource(ContractDeadlines::default());
 }
 
 /// interaction with contract phone to start a contract
+#[allow(clippy::type_complexity)]
 fn interaction_contract_phone(
     mut interaction_commands: EventReader<Interact>,
     contract_phone_query: Query<
-        (Entity, &ContractId, &ContractType, Option<&ContractPayout>),
+        (
+            Entity,
+            &ContractId,
+            &ContractType,
+            Option<&ContractPayout>,
+            Option<&ContractTimeLimit>,
+        ),
         With<ContractPhone>,
     >,
     mut contracts: ResMut<Contracts>,
+    mut deadlines: ResMut<ContractDeadlines>,
     mut interacted: EventWriter<ContractPhoneInteracted>,
 ) {
     for command in interaction_commands.read() {
         // filter for commands on ContractPhone entities only
-        if let Ok((phone, contract_id, contract_type, payout)) =
+        if let Ok((phone, contract_id, contract_type, payout, time_limit)) =
             contract_phone_query.get(command.interaction_entity)
         {
             debug!(
@@ -231,6 +500,12 @@ fn interaction_contract_phone(
                 },
             );
             debug!("added contract to contracts resource");
+            if let Some(time_limit) = time_limit {
+                deadlines.map.insert(
+                    *contract_id,
+                    Timer::from_seconds(time_limit.0, TimerMode::Once),
+                );
+            }
             interacted.send(ContractPhoneInteracted {
                 contract_id: *contract_id,
                 operator_entity: command.operator_entity,
@@ -261,18 +536,27 @@ fn contract_accepted(
                                 contract_id,
                             });
                         }
-                        ContractType::SecureIntel => todo!(),
-                        ContractType::EliminateHVT => todo!(),
-                        ContractType::DestroySupplies => todo!(),
-                        ContractType::RescueHostage => todo!(),
-                        ContractType::RaidWeaponStash => todo!(),
-                        ContractType::CargoDelivery => todo!(),
-                        ContractType::CargoShipment => todo!(),
-                        ContractType::SecureNuclearMaterials => todo!(),
-                        ContractType::SignalIntelligence => todo!(),
-                        ContractType::HuntSquad => todo!(),
+                        // the intel is hidden by the secure intel plugin
+                        ContractType::SecureIntel => (),
+                        // the HVT is spawned by the eliminate HVT plugin
+                        ContractType::EliminateHVT => (),
+                        // the supply crates are marked by the destroy supplies plugin
+                        ContractType::DestroySupplies => (),
+                        // the hostage is taken by the rescue hostage plugin
+                        ContractType::RescueHostage => (),
+                        // the stash is spawned by the raid weapon stash plugin
+                        ContractType::RaidWeaponStash => (),
+                        // the truck is spawned by the cargo delivery plugin
+                        ContractType::CargoDelivery => (),
+                        ContractType::CargoShipment => (),
+                        // the container is spawned by the secure nuclear materials plugin
+                        ContractType::SecureNuclearMaterials => (),
+                        // the uplinks are set up by the signal intelligence plugin
+                        ContractType::SignalIntelligence => (),
+                        // the target squad is picked by the hunt squad plugin
+                        ContractType::HuntSquad => (),
                     },
-                    None => todo!(),
+                    None => warn!("no contract {:?} for accepted phone", contract_id),
                 }
             });
     }
@@ -435,54 +719,494 @@ fn secure_supplies_interacted(
     }
 }
 
+/// payout to each member of the squad holding the contract, finish contract
+fn complete_contract(
+    mut events: EventReader<ContractCompleted>,
+    mut contracts: ResMut<Contracts>,
+    squads: Res<Squads>,
+    squad_operators: Query<(Entity, &SquadId), With<Operator>>,
+    mut receive_money: EventWriter<ReceiveMoney>,
+    mut finished_contract: EventWriter<FinishedContract>,
+) {
+    for completed in events.read() {
+        let Some(contract) = contracts.map.get_mut(&completed.contract_id) else {
+            continue;
+        };
+        if contract.contract_state.is_over() {
+            continue;
+        }
+        debug!("contract {:?} completed", completed.contract_id);
+        contract.contract_state = ContractState::Finished;
+        if let Some(squad_id) = contract_squad(&squads, completed.contract_id) {
+            squad_operators
+                .iter()
+                .filter(|(_, id)| (*id).eq(squad_id))
+                .for_each(|(operator, _)| {
+                    receive_money.send(ReceiveMoney {
+                        amount: contract.contract_payout,
+                        receiver: operator,
+                    });
+                });
+        }
+        finished_contract.send(FinishedContract {
+            contract_id: completed.contract_id,
+        });
+    }
+}
+
+fn fail_contract(
+    mut events: EventReader<FailContract>,
+    mut contracts: ResMut<Contracts>,
+    mut contract_failed: EventWriter<ContractFailed>,
+) {
+    for fail in events.read() {
+        let Some(contract) = contracts.map.get_mut(&fail.contract_id) else {
+            continue;
+        };
+        if contract.contract_state.is_over() {
+            continue;
+        }
+        debug!("contract {:?} failed", fail.contract_id);
+        contract.contract_state = ContractState::Failed;
+        contract_failed.send(ContractFailed {
+            contract_id: fail.contract_id,
+        });
+    }
+}
+
+fn cancel_contract(
+    mut events: EventReader<CancelContract>,
+    mut contracts: ResMut<Contracts>,
+    mut contract_cancelled: EventWriter<ContractCancelled>,
+) {
+    for cancel in events.read() {
+        let Some(contract) = contracts.map.get_mut(&cancel.contract_id) else {
+            continue;
+        };
+        if contract.contract_state.is_over() {
+            continue;
+        }
+        debug!("contract {:?} cancelled", cancel.contract_id);
+        contract.contract_state = ContractState::Cancelled;
+        contract_cancelled.send(ContractCancelled {
+            contract_id: cancel.contract_id,
+        });
+    }
+}
+
+/// contracts held by a squad fail once their time limit is up
+fn expire_contracts(
+    time: Res<Time>,
+    contracts: Res<Contracts>,
+    squads: Res<Squads>,
+    mut deadlines: ResMut<ContractDeadlines>,
+    mut fail: EventWriter<FailContract>,
+) {
+    deadlines.map.retain(|contract_id, deadline| {
+        let Some(contract) = contracts.map.get(contract_id) else {
+            return false;
+        };
+        if contract.contract_state.is_over() {
+            return false;
+        }
+        if contract_squad(&squads, *contract_id).is_none() {
+            return true;
+        }
+        if !deadline.tick(time.delta()).finished() {
+            return true;
+        }
+        debug!("contract {:?} expired", contract_id);
+        fail.send(FailContract {
+            contract_id: *contract_id,
+        });
+        false
+    });
+}
+
+/// the contract of a squad fails once none of its operators is left alive
+fn squad_wiped_out(
+    mut died: EventReader<EntityDied>,
+    squads: Res<Squads>,
+    operators: Query<(Entity, &SquadId), With<Operator>>,
+    mut fail: EventWriter<FailContract>,
+) {
+    let deaths: Vec<(Entity, &SquadId)> = died
+        .read()
+        .filter(|event| event.death_kind == DeathKind::Operator)
+        .filter_map(|event| event.death_squad.as_ref().map(|squad| (event.death, squad)))
+        .collect();
+    let mut wiped_out: Vec<&SquadId> = Vec::new();
+    for (_, squad_id) in deaths.iter() {
+        let survivors = operators
+            .iter()
+            .filter(|(_, id)| id == squad_id)
+            .filter(|(operator, _)| !deaths.iter().any(|(death, _)| death == operator))
+            .count();
+        if survivors == 0 && !wiped_out.contains(squad_id) {
+            wiped_out.push(squad_id);
+        }
+    }
+    for squad_id in wiped_out {
+        let Some(contract_id) = squads
+            .map
+            .get(squad_id)
+            .and_then(|squad| squad.current_contract)
+        else {
+            continue;
+        };
+        debug!("squad {:?} wiped out", squad_id);
+        fail.send(FailContract { contract_id });
+    }
+}
+
+/// a failed or cancelled contract does not reveal anything anymore
+fn clear_ended_contract_areas(
+    mut commands: Commands,
+    mut failed: EventReader<ContractFailed>,
+    mut cancelled: EventReader<ContractCancelled>,
+    areas: Query<(Entity, &ContractId), With<ContractGeneralArea>>,
+) {
+    let ended = ended_contracts(&mut failed, &mut cancelled);
+    for (area, contract_id) in areas.iter() {
+        if ended.contains(contract_id) {
+            debug!("removing general area of contract {:?}", contract_id);
+            commands.entity(area).despawn_recursive();
+        }
+    }
+}
+
 // TODO: maybe implement a system for each other contract that reacts to state changes similar to the one above
 
-fn bye_contract_system(mut commands: Commands) {
+fn bye_contract_system(mut commands: Commands, areas: Query<Entity, With<ContractGeneralArea>>) {
     debug!("stopping {}", NAME);
     commands.remove_resource::<Contracts>();
+    commands.remove_resource::<ContractDeadlines>();
+    for area in areas.iter() {
+        commands.entity(area).despawn_recursive();
+    }
 }
 
 // helper functions
+
+/// contracts that failed or got cancelled, for the contract plugins to drop what they keep
+/// track of
+pub fn ended_contracts(
+    failed: &mut EventReader<ContractFailed>,
+    cancelled: &mut EventReader<ContractCancelled>,
+) -> Vec<ContractId> {
+    failed
+        .read()
+        .map(|event| event.contract_id)
+        .chain(cancelled.read().map(|event| event.contract_id))
+        .collect()
+}
+
+/// squad that currently holds the contract
+pub fn contract_squad(squads: &Squads, contract_id: ContractId) -> Option<&SquadId> {
+    squads
+        .map
+        .iter()
+        .find(|(_, squad)| squad.current_contract == Some(contract_id))
+        .map(|(squad_id, _)| squad_id)
+}
+
 fn initial_state(contract_type: ContractType) -> ContractState {
     match contract_type {
         ContractType::SecureSupplies => {
             ContractState::SecureSupplies(SecureSuppliesState::default())
         }
-        ContractType::SecureIntel => todo!(),
-        ContractType::EliminateHVT => todo!(),
-        ContractType::DestroySupplies => todo!(),
-        ContractType::RescueHostage => todo!(),
-        ContractType::RaidWeaponStash => todo!(),
-        ContractType::CargoDelivery => todo!(),
-        ContractType::CargoShipment => todo!(),
-        ContractType::SecureNuclearMaterials => todo!(),
-        ContractType::SignalIntelligence => todo!(),
-        ContractType::HuntSquad => todo!(),
+        ContractType::SecureIntel => ContractState::SecureIntel(SecureIntelState::default()),
+        ContractType::EliminateHVT => ContractState::EliminateHVT(EliminateHVTState::default()),
+        ContractType::DestroySupplies => {
+            ContractState::DestroySupplies(DestroySuppliesState::default())
+        }
+        ContractType::RescueHostage => ContractState::RescueHostage(RescueHostageState::default()),
+        ContractType::RaidWeaponStash => {
+            ContractState::RaidWeaponStash(RaidWeaponStashState::default())
+        }
+        ContractType::CargoDelivery | ContractType::CargoShipment => {
+            ContractState::CargoDelivery(CargoDeliveryState::default())
+        }
+        ContractType::SecureNuclearMaterials => {
+            ContractState::SecureNuclearMaterials(SecureNuclearMaterialsState::default())
+        }
+        ContractType::SignalIntelligence => {
+            ContractState::SignalIntelligence(SignalIntelligenceState::default())
+        }
+        ContractType::HuntSquad => ContractState::HuntSquad(HuntSquadState::default()),
     }
 }
 
+/// `Contracts` with the contract in the given state and `Squads` where squad 1 holds it, the
+/// usual setup for tests of the contract plugins
+#[cfg(test)]
+pub fn insert_held_contract(
+    app: &mut App,
+    contract_id: ContractId,
+    contract_type: ContractType,
+    contract_state: ContractState,
+) {
+    let mut contracts = Contracts::default();
+    contracts.map.insert(
+        contract_id,
+        Contract {
+            contract_type,
+            contract_state,
+            contract_payout: 2000,
+        },
+    );
+    app.insert_resource(contracts);
+    let mut squads = Squads::default();
+    squads.map.insert(
+        SquadId(1),
+        crate::squad::Squad {
+            current_contract: Some(contract_id),
+            ..default()
+        },
+    );
+    app.insert_resource(squads);
+}
+
 // tests
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
-    //use super::*;
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn should_go_through_eliminate_hvt_states() {
+        // given
+        let mut state = EliminateHVTState::default();
+
+        // when
+        let located = state.next();
+        let ContractState::EliminateHVT(mut state) = located else {
+            panic!("unexpected state {:?}", located);
+        };
+        let eliminated = state.next();
+        let ContractState::EliminateHVT(mut state) = eliminated else {
+            panic!("unexpected state {:?}", eliminated);
+        };
+
+        // then
+        assert_eq!(
+            ContractState::EliminateHVT(EliminateHVTState::Located),
+            located
+        );
+        assert_eq!(
+            ContractState::EliminateHVT(EliminateHVTState::Eliminated),
+            eliminated
+        );
+        assert_eq!(ContractState::Finished, state.next());
+    }
+
+    #[test]
+    fn should_pay_out_squad_once_when_contract_completed() {
+        // given
+        let mut app = App::new();
+        app.add_event::<ContractCompleted>();
+        app.add_event::<ReceiveMoney>();
+        app.add_event::<FinishedContract>();
+        app.add_systems(Update, complete_contract);
+        let contract_id = ContractId(7);
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::EliminateHVT,
+            ContractState::EliminateHVT(EliminateHVTState::Eliminated),
+        );
+        let member = app.world_mut().spawn((Operator, SquadId(1))).id();
+        app.world_mut().spawn((Operator, SquadId(2)));
+
+        // when
+        for _ in 0..2 {
+            app.world_mut()
+                .resource_mut::<Events<ContractCompleted>>()
+                .send(ContractCompleted { contract_id });
+            app.update();
+        }
+
+        // then
+        assert_eq!(
+            ContractState::Finished,
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        let money_events = app.world().resource::<Events<ReceiveMoney>>();
+        let mut money_reader = money_events.get_cursor();
+        assert_eq!(
+            vec![&ReceiveMoney {
+                amount: 2000,
+                receiver: member,
+            }],
+            money_reader.read(money_events).collect::<Vec<_>>()
+        );
+        let finished_events = app.world().resource::<Events<FinishedContract>>();
+        let mut finished_reader = finished_events.get_cursor();
+        assert_eq!(1, finished_reader.read(finished_events).count());
+    }
+
+    fn held_contract_app(contract_id: ContractId) -> App {
+        let mut app = App::new();
+        app.add_event::<FailContract>();
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::SecureIntel,
+            ContractState::SecureIntel(SecureIntelState::Started),
+        );
+        app
+    }
+
+    #[test]
+    fn should_fail_contract_when_time_limit_is_up() {
+        // given
+        let contract_id = ContractId(71);
+        let mut app = held_contract_app(contract_id);
+        app.init_resource::<Time>();
+        app.add_systems(Update, expire_contracts);
+        let mut deadlines = ContractDeadlines::default();
+        deadlines
+            .map
+            .insert(contract_id, Timer::from_seconds(60.0, TimerMode::Once));
+        app.insert_resource(deadlines);
+
+        // when
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(30));
+        app.update();
+
+        // then
+        assert!(app.world().resource::<Events<FailContract>>().is_empty());
+
+        // when
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(30));
+        app.update();
+
+        // then
+        assert!(app.world().resource::<ContractDeadlines>().map.is_empty());
+        let fail_events = app.world().resource::<Events<FailContract>>();
+        let mut fail_reader = fail_events.get_cursor();
+        assert_eq!(
+            vec![&FailContract { contract_id }],
+            fail_reader.read(fail_events).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_fail_contract_when_last_squad_member_died() {
+        // given
+        let contract_id = ContractId(72);
+        let mut app = held_contract_app(contract_id);
+        app.add_event::<EntityDied>();
+        app.add_systems(Update, squad_wiped_out);
+        let first = app.world_mut().spawn((Operator, SquadId(1))).id();
+        let second = app.world_mut().spawn((Operator, SquadId(1))).id();
+        let operator_died = |death: Entity| EntityDied {
+            death,
+            killer: None,
+            killer_squad: None,
+            death_squad: Some(SquadId(1)),
+            death_kind: DeathKind::Operator,
+            death_name: None,
+            weapon: None,
+            assists: vec![],
+        };
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<EntityDied>>()
+            .send(operator_died(first));
+        app.update();
+        app.world_mut().despawn(first);
+
+        // then
+        assert!(app.world().resource::<Events<FailContract>>().is_empty());
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<EntityDied>>()
+            .send(operator_died(second));
+        app.update();
+
+        // then
+        let fail_events = app.world().resource::<Events<FailContract>>();
+        let mut fail_reader = fail_events.get_cursor();
+        assert_eq!(
+            vec![&FailContract { contract_id }],
+            fail_reader.read(fail_events).collect::<Vec<_>>()
+        );
+    }
 
-    /*
     #[test]
-    fn should_test_something() {
+    fn should_cancel_contract_once() {
         // given
-        //let mut _app = App::new();
+        let contract_id = ContractId(73);
+        let mut app = held_contract_app(contract_id);
+        app.add_event::<CancelContract>();
+        app.add_event::<ContractCancelled>();
+        app.add_systems(Update, cancel_contract);
 
         // when
-        //app.add_event::<HealthDamageReceived>();
-        //app.add_systems(Update, damage_received_listener);
-        //let entity = app.borrow_mut().world.spawn(Health(100)).id();
-        //app.borrow_mut().world.resource_mut::<Events<HealthDamageReceived>>().send(HealthDamageReceived { entity, damage: 10 });
-        //app.update();
+        for _ in 0..2 {
+            app.world_mut()
+                .resource_mut::<Events<CancelContract>>()
+                .send(CancelContract { contract_id });
+            app.update();
+        }
+
+        // then
+        assert_eq!(
+            ContractState::Cancelled,
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        let cancelled_events = app.world().resource::<Events<ContractCancelled>>();
+        let mut cancelled_reader = cancelled_events.get_cursor();
+        assert_eq!(
+            vec![&ContractCancelled { contract_id }],
+            cancelled_reader.read(cancelled_events).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_remove_general_areas_of_ended_contracts() {
+        // given
+        let mut app = App::new();
+        app.add_event::<ContractFailed>();
+        app.add_event::<ContractCancelled>();
+        app.add_systems(Update, clear_ended_contract_areas);
+        let failed = app
+            .world_mut()
+            .spawn((ContractGeneralArea::default(), ContractId(74)))
+            .id();
+        let cancelled = app
+            .world_mut()
+            .spawn((ContractGeneralArea::default(), ContractId(75)))
+            .id();
+        let running = app
+            .world_mut()
+            .spawn((ContractGeneralArea::default(), ContractId(76)))
+            .id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<ContractFailed>>()
+            .send(ContractFailed {
+                contract_id: ContractId(74),
+            });
+        app.world_mut()
+            .resource_mut::<Events<ContractCancelled>>()
+            .send(ContractCancelled {
+                contract_id: ContractId(75),
+            });
+        app.update();
 
         // then
-        //assert!(app.world.get::<Health>(entity).is_some());
-        //assert_eq!(app.world.get::<Health>(entity).unwrap().0, 90);
+        assert!(app.world().get_entity(failed).is_err());
+        assert!(app.world().get_entity(cancelled).is_err());
+        assert!(app.world().get_entity(running).is_ok());
     }
-    */
 }
@@ -23,13 +23,20 @@ impl Plugin for DamagePlugin {
             // types
             .register_type::<Damage>()
             .register_type::<DamageOrigin>()
+            .register_type::<DamageWeapon>()
             .register_type::<HurtBox>()
             .register_type::<HitBox>()
             // systems
             .add_systems(OnEnter(Raid), start_damage_system)
             .add_systems(
                 Update,
-                (update_damage_system).run_if(in_state(AppState::Raid)),
+                (
+                    inherit_damage_origin,
+                    damage_origin_added,
+                    update_damage_system,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
             )
             .add_systems(OnExit(AppState::Raid), bye_damage_system)
             .add_event::<ArmorDamageReceived>()
@@ -55,6 +62,10 @@ pub struct Damage(pub i32);
 #[derive(Component, Debug, PartialEq, Reflect, InspectorOptions)]
 pub struct DamageOrigin(pub Entity);
 
+/// weapon (or any other tool like grenades or gas) the damage was dealt with
+#[derive(Component, Debug, PartialEq, Reflect, InspectorOptions)]
+pub struct DamageWeapon(pub Entity);
+
 // Resources
 
 // Events
@@ -63,6 +74,7 @@ pub struct ArmorDamageReceived {
     pub entity: Entity,
     pub damage: i32,
     pub dealer: Option<Entity>,
+    pub weapon: Option<Entity>,
 }
 
 #[derive(Event, Debug, PartialEq)]
@@ -70,6 +82,7 @@ pub struct HealthDamageReceived {
     pub entity: Entity,
     pub damage: i32,
     pub dealer: Option<Entity>,
+    pub weapon: Option<Entity>,
 }
 
 // Systems
@@ -77,6 +90,43 @@ fn start_damage_system(mut _commands: Commands) {
     debug!("starting {}", NAME);
 }
 
+/// hitboxes attached to a damage dealing parent (e.g. the cube of a projectile) inherit the origin
+/// chain of said parent, so the hit can be attributed to the shooter and weapon later on.
+#[allow(clippy::type_complexity)]
+fn inherit_damage_origin(
+    mut commands: Commands,
+    added_hitboxes: Query<(Entity, &Parent), (Added<HitBox>, Without<DamageOrigin>)>,
+    parents: Query<(&DamageOrigin, Option<&DamageWeapon>, Option<&SquadId>)>,
+) {
+    for (hitbox, parent) in added_hitboxes.iter() {
+        if let Ok((origin, weapon, squad_id)) = parents.get(parent.get()) {
+            debug!("hitbox({hitbox}) inherits damage origin ({})", origin.0);
+            commands.entity(hitbox).insert(DamageOrigin(origin.0));
+            if let Some(w) = weapon {
+                commands.entity(hitbox).insert(DamageWeapon(w.0));
+            }
+            if let Some(s) = squad_id {
+                commands.entity(hitbox).insert(s.clone());
+            }
+        }
+    }
+}
+
+/// completes the origin chain by the squad of the damage origin, if not present already
+fn damage_origin_added(
+    mut commands: Commands,
+    damage_origin_added: Query<(Entity, &DamageOrigin, Option<&SquadId>), Added<DamageOrigin>>,
+    squad_id_query: Query<&SquadId>,
+) {
+    for (added, damage_origin, squad_id) in damage_origin_added.iter() {
+        if squad_id.is_some() {
+            continue;
+        } else if let Ok(s) = squad_id_query.get(damage_origin.0) {
+            commands.entity(added).insert(s.clone());
+        }
+    }
+}
+
 // TODO: this system to implement friendly fire???
 #[allow(clippy::type_complexity)]
 fn update_damage_system(
@@ -86,6 +136,7 @@ fn update_damage_system(
         &Damage,
         &GlobalTransform,
         Option<&DamageOrigin>,
+        Option<&DamageWeapon>,
         Option<&SquadId>,
     )>,
     mut hurtbox_query: Query<(
@@ -101,7 +152,7 @@ fn update_damage_system(
     mut commands: Commands,
 ) {
     debug!("updating {}", NAME);
-    for (hit_entity, hitbox, damage, hit_transform, damage_origin, hit_squad_id) in
+    for (hit_entity, hitbox, damage, hit_transform, damage_origin, damage_weapon, hit_squad_id) in
         hitbox_query.iter_mut()
     {
         let transformed_hit_box = Aabb3d::new(hit_transform.translation(), hitbox.0.half_size());
@@ -115,6 +166,7 @@ fn update_damage_system(
             // dont hit yourself if overlap occours
             if hit_entity != hurt_entity && transformed_hit_box.intersects(&transformed_hurt_box) {
                 let dealer = damage_origin.map(|dealer_ref| dealer_ref.0);
+                let weapon = damage_weapon.map(|weapon_ref| weapon_ref.0);
                 if let (Some(hit_squad), Some(hurt_squad)) = (hit_squad_id, hurt_squad_id) {
                     if hit_squad.eq(hurt_squad) && !hit_entity.eq(&hurt_entity) {
                         debug!(
@@ -135,6 +187,7 @@ fn update_damage_system(
                         entity: hurt_entity,
                         damage: y,
                         dealer,
+                        weapon,
                     };
                     armor_sender.send(event);
                     remaining_damage = damage.0 - y;
@@ -147,6 +200,7 @@ fn update_damage_system(
                         entity: hurt_entity,
                         damage: y,
                         dealer,
+                        weapon,
                     };
                     health_sender.send(event);
                     remaining_damage -= y;
@@ -230,6 +284,7 @@ mod tests {
                 entity: hurt_entity,
                 damage: 10,
                 dealer: Option::None,
+                weapon: Option::None,
             }),
             armor_damage_received
         );
@@ -300,6 +355,7 @@ mod tests {
                 entity: hurt_entity,
                 damage: 100,
                 dealer: Option::None,
+                weapon: Option::None,
             }),
             armor_damage_received
         );
@@ -308,6 +364,7 @@ mod tests {
                 entity: hurt_entity,
                 damage: 10,
                 dealer: Option::None,
+                weapon: Option::None,
             }),
             health_damage_received
         );
@@ -378,6 +435,7 @@ mod tests {
                 entity: hurt_entity,
                 damage: 100,
                 dealer: Option::None,
+                weapon: Option::None,
             }),
             armor_damage_received
         );
@@ -386,9 +444,79 @@ mod tests {
                 entity: hurt_entity,
                 damage: 100,
                 dealer: Option::None,
+                weapon: Option::None,
             }),
             health_damage_received
         );
         assert!(app.world().get::<Damage>(hit_entity).is_none());
     }
+
+    #[test]
+    fn should_attribute_damage_to_origin_and_weapon() {
+        // given
+        let mut app = App::new();
+        app.add_event::<ArmorDamageReceived>();
+        app.add_event::<HealthDamageReceived>();
+        app.add_systems(
+            Update,
+            (
+                inherit_damage_origin,
+                damage_origin_added,
+                update_damage_system,
+            )
+                .chain(),
+        );
+        let shooter = app.world_mut().spawn(SquadId(1)).id();
+        let weapon = app.world_mut().spawn_empty().id();
+        let projectile = app
+            .world_mut()
+            .spawn((DamageOrigin(shooter), DamageWeapon(weapon)))
+            .id();
+        let hit_entity = app
+            .world_mut()
+            .spawn((
+                HitBox(Aabb3d::new(Vec3::default(), Vec3::ONE)),
+                Damage(10),
+                Transform::default(),
+            ))
+            .set_parent(projectile)
+            .id();
+        let hurt_entity = app
+            .world_mut()
+            .spawn((
+                HurtBox(Aabb3d::new(Vec3::default(), Vec3::ONE)),
+                Health(100),
+                Transform::default(),
+            ))
+            .id();
+
+        // when
+        app.update();
+
+        // then
+        assert_eq!(
+            Some(&DamageOrigin(shooter)),
+            app.world().get::<DamageOrigin>(hit_entity)
+        );
+        assert_eq!(
+            Some(&DamageWeapon(weapon)),
+            app.world().get::<DamageWeapon>(hit_entity)
+        );
+        assert_eq!(Some(&SquadId(1)), app.world().get::<SquadId>(hit_entity));
+
+        let health_damage_received_events = app.world().resource::<Events<HealthDamageReceived>>();
+        let mut health_damage_received_reader = health_damage_received_events.get_cursor();
+        let health_damage_received = health_damage_received_reader
+            .read(health_damage_received_events)
+            .next();
+        assert_eq!(
+            Some(&HealthDamageReceived {
+                entity: hurt_entity,
+                damage: 10,
+                dealer: Some(shooter),
+                weapon: Some(weapon),
+            }),
+            health_damage_received
+        );
+    }
 }
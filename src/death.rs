@@ -1,11 +1,18 @@
 use bevy::app::Plugin;
+use bevy::utils::HashMap;
 
+use crate::damage::{ArmorDamageReceived, HealthDamageReceived};
+use crate::exfil::Operator;
+use crate::raid::Enemy;
+use crate::squad::SquadId;
 use crate::AppState;
 use crate::AppState::Raid;
 use bevy::prelude::*;
 
 // Constants
 const NAME: &str = "death";
+/// time window in seconds in which dealt damage counts as an assist
+const ASSIST_WINDOW_SECS: f32 = 10.0;
 
 // Plugin
 /// plugin to deal with death related components/systems/events
@@ -21,7 +28,9 @@ impl Plugin for DeathPlugin {
             .add_systems(OnEnter(Raid), start_death)
             .add_systems(
                 Update,
-                (update_dying, update_death).run_if(in_state(AppState::Raid)),
+                (record_damage_dealers, update_dying, update_death)
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
             )
             .add_systems(OnExit(AppState::Raid), bye_death);
     }
@@ -31,34 +40,109 @@ impl Plugin for DeathPlugin {
 
 // Resources
 
+/// book keeping of who dealt damage to whom and when, used for handing out assists
+#[derive(Resource, Default, Debug)]
+pub struct DamageDealers {
+    /// damaged entity -> (dealer, elapsed seconds at time of damage)
+    pub map: HashMap<Entity, Vec<(Entity, f32)>>,
+}
+
 // Events
 #[derive(Event, Debug, PartialEq)]
 pub struct EntityDie {
     pub dying: Entity,
     /// killer is the final entity that delivered damage that lead to death
     pub killer: Option<Entity>,
+    /// weapon the final damage was delivered with
+    pub weapon: Option<Entity>,
 }
 
 #[derive(Event, Debug, PartialEq)]
 pub struct EntityDied {
     pub death: Entity,
     pub killer: Option<Entity>,
+    /// squad of the killer at the time of death
+    pub killer_squad: Option<SquadId>,
+    /// squad of the deceased at the time of death
+    pub death_squad: Option<SquadId>,
+    /// kind of the deceased, as the entity itself is gone soon after this event
+    pub death_kind: DeathKind,
+    /// name of the deceased, for the same reason as above
+    pub death_name: Option<Name>,
+    pub weapon: Option<Entity>,
+    /// other entities that damaged the deceased within the assist window
+    pub assists: Vec<Entity>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum DeathKind {
+    Operator,
+    Enemy,
+    #[default]
+    Other,
 }
 
 // Systems
-fn start_death(mut _commands: Commands) {
+fn start_death(mut commands: Commands) {
     debug!("starting {}", NAME);
+    commands.insert_resource(DamageDealers::default());
 }
 
-/// system for book keeping.
-fn update_dying(mut dying: EventReader<EntityDie>, mut deaths: EventWriter<EntityDied>) {
+/// keeps track of recent damage dealers per damaged entity
+fn record_damage_dealers(
+    time: Res<Time>,
+    mut health_damage: EventReader<HealthDamageReceived>,
+    mut armor_damage: EventReader<ArmorDamageReceived>,
+    mut dealers: ResMut<DamageDealers>,
+) {
+    let now = time.elapsed_secs();
+    let health_dealers = health_damage
+        .read()
+        .filter_map(|event| event.dealer.map(|dealer| (event.entity, dealer)));
+    let armor_dealers = armor_damage
+        .read()
+        .filter_map(|event| event.dealer.map(|dealer| (event.entity, dealer)));
+    for (damaged, dealer) in health_dealers.chain(armor_dealers) {
+        let entries = dealers.map.entry(damaged).or_default();
+        entries.retain(|(_, at)| now - at <= ASSIST_WINDOW_SECS);
+        entries.push((dealer, now));
+    }
+}
+
+/// system for book keeping. enriches the death with everything needed for kill credit.
+#[allow(clippy::type_complexity)]
+fn update_dying(
+    time: Res<Time>,
+    mut dying: EventReader<EntityDie>,
+    mut deaths: EventWriter<EntityDied>,
+    mut dealers: ResMut<DamageDealers>,
+    squads: Query<&SquadId>,
+    kinds: Query<(Has<Operator>, Has<Enemy>)>,
+    names: Query<&Name>,
+) {
     debug!("updating {}", NAME);
+    let now = time.elapsed_secs();
     for event in dying.read() {
         debug!("somebody is about to die: {}", event.dying);
-        // FIXME: for now just relay the event, could be more sophisticated later
+        let assists = dealers
+            .map
+            .remove(&event.dying)
+            .map(|entries| assists_within_window(&entries, event.killer, now))
+            .unwrap_or_default();
+        let death_kind = match kinds.get(event.dying) {
+            Ok((true, _)) => DeathKind::Operator,
+            Ok((_, true)) => DeathKind::Enemy,
+            _ => DeathKind::Other,
+        };
         deaths.send(EntityDied {
             death: event.dying,
             killer: event.killer,
+            killer_squad: event.killer.and_then(|k| squads.get(k).ok()).cloned(),
+            death_squad: squads.get(event.dying).ok().cloned(),
+            death_kind,
+            death_name: names.get(event.dying).ok().cloned(),
+            weapon: event.weapon,
+            assists,
         });
     }
 }
@@ -75,34 +159,112 @@ fn update_death(mut deaths: EventReader<EntityDied>, mut commands: Commands) {
     }
 }
 
-fn bye_death(mut _commands: Commands) {
+fn bye_death(mut commands: Commands) {
     debug!("stopping {}", NAME);
+    commands.remove_resource::<DamageDealers>();
 }
 
 // helper functions
 
+/// unique dealers within the assist window, the killer is not assisting himself
+fn assists_within_window(
+    entries: &[(Entity, f32)],
+    killer: Option<Entity>,
+    now: f32,
+) -> Vec<Entity> {
+    let mut assists: Vec<Entity> = Vec::new();
+    entries
+        .iter()
+        .filter(|(_, at)| now - at <= ASSIST_WINDOW_SECS)
+        .map(|(dealer, _)| *dealer)
+        .filter(|dealer| Some(*dealer) != killer)
+        .for_each(|dealer| {
+            if !assists.contains(&dealer) {
+                assists.push(dealer);
+            }
+        });
+    assists
+}
+
 // tests
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
-    //use super::*;
+    use super::*;
+
+    #[test]
+    fn should_only_assist_within_window_and_without_killer() {
+        // given
+        let mut world = World::new();
+        let killer = world.spawn_empty().id();
+        let assist = world.spawn_empty().id();
+        let too_late = world.spawn_empty().id();
+        let entries = vec![
+            (too_late, 1.0),
+            (assist, 15.0),
+            (killer, 16.0),
+            (assist, 17.0),
+        ];
+
+        // when
+        let actual = assists_within_window(&entries, Some(killer), 20.0);
+
+        // then
+        assert_eq!(vec![assist], actual);
+    }
 
-    /*
     #[test]
-    fn should_test_something() {
+    fn should_credit_kill_with_squad_and_weapon() {
         // given
-        //let mut _app = App::new();
+        let mut app = App::new();
+        app.add_event::<HealthDamageReceived>();
+        app.add_event::<ArmorDamageReceived>();
+        app.add_event::<EntityDie>();
+        app.add_event::<EntityDied>();
+        app.init_resource::<Time>();
+        app.init_resource::<DamageDealers>();
+        app.add_systems(Update, (record_damage_dealers, update_dying).chain());
+        let killer = app.world_mut().spawn((Operator, SquadId(1))).id();
+        let assist = app.world_mut().spawn((Operator, SquadId(1))).id();
+        let weapon = app.world_mut().spawn_empty().id();
+        let dying = app
+            .world_mut()
+            .spawn((Enemy, SquadId(2), Name::new("Zombie")))
+            .id();
 
         // when
-        //app.add_event::<HealthDamageReceived>();
-        //app.add_systems(Update, damage_received_listener);
-        //let entity = app.borrow_mut().world.spawn(Health(100)).id();
-        //app.borrow_mut().world.resource_mut::<Events<HealthDamageReceived>>().send(HealthDamageReceived { entity, damage: 10 });
-        //app.update();
+        app.world_mut()
+            .resource_mut::<Events<HealthDamageReceived>>()
+            .send(HealthDamageReceived {
+                entity: dying,
+                damage: 50,
+                dealer: Some(assist),
+                weapon: None,
+            });
+        app.update();
+        app.world_mut()
+            .resource_mut::<Events<EntityDie>>()
+            .send(EntityDie {
+                dying,
+                killer: Some(killer),
+                weapon: Some(weapon),
+            });
+        app.update();
 
         // then
-        //assert!(app.world.get::<Health>(entity).is_some());
-        //assert_eq!(app.world.get::<Health>(entity).unwrap().0, 90);
+        let died_events = app.world().resource::<Events<EntityDied>>();
+        let mut died_reader = died_events.get_cursor();
+        let actual = died_reader.read(died_events).next();
+        let expected = EntityDied {
+            death: dying,
+            killer: Some(killer),
+            killer_squad: Some(SquadId(1)),
+            death_squad: Some(SquadId(2)),
+            death_kind: DeathKind::Enemy,
+            death_name: Some(Name::new("Zombie")),
+            weapon: Some(weapon),
+            assists: vec![assist],
+        };
+        assert_eq!(Some(&expected), actual);
     }
-    */
 }
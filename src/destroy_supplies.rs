@@ -0,0 +1,417 @@
+use bevy::app::Plugin;
+use bevy::math::bounding::Aabb3d;
+use bevy::utils::HashMap;
+
+use crate::contracts::{
+    contract_squad, ended_contracts, ContractAccepted, ContractCancelled, ContractCompleted,
+    ContractFailed, ContractGeneralArea, ContractId, ContractSpotlight, ContractState,
+    ContractStateMachine, ContractType, Contracts,
+};
+use crate::damage::{Damage, DamageOrigin, HitBox, HurtBox};
+use crate::death::EntityDied;
+use crate::exfil::Operator;
+use crate::health::Health;
+use crate::interaction::{HoldInteractable, Interact, Interactable};
+use crate::squad::{SquadId, Squads};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "destroy supplies";
+/// seconds the interaction key has to be held down to plant a charge
+const PLANT_CHARGE_SECS: f32 = 3.0;
+const FUSE_SECS: f32 = 5.0;
+const EXPLOSION_RADIUS: f32 = 3.0;
+const EXPLOSION_DAMAGE: i32 = 400;
+/// seconds the explosion stays visible
+const EXPLOSION_SECS: f32 = 0.5;
+/// crates can take a few bullets, but not a charge
+const CRATE_HEALTH: i32 = 300;
+
+// Plugin
+/// contract to blow up supply crates with explosive charges
+pub struct DestroySuppliesPlugin;
+
+impl Plugin for DestroySuppliesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_destroy_supplies)
+            .add_systems(
+                Update,
+                (
+                    mark_supply_crates,
+                    plant_charge,
+                    burn_fuse,
+                    fade_explosion,
+                    supply_crate_destroyed,
+                    supplies_contract_ended,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_destroy_supplies);
+    }
+}
+
+// Components
+
+/// supply crate of the destroy supplies contract with the same `ContractId`
+#[derive(Component, Debug)]
+pub struct SupplyCrate;
+
+/// planted explosive charge, detonates once the fuse burned down
+#[derive(Component, Debug)]
+pub struct Charge {
+    pub fuse: Timer,
+    /// operator that planted the charge, dealer of the explosion damage
+    pub planter: Entity,
+}
+
+#[derive(Component, Debug)]
+pub struct Explosion(pub Timer);
+
+// Resources
+
+/// contract of every marked crate, destroyed crates are gone
+#[derive(Resource, Default)]
+struct SupplyCrates {
+    map: HashMap<Entity, ContractId>,
+}
+
+// Events
+
+// Systems
+fn start_destroy_supplies(mut commands: Commands) {
+    debug!("starting {}", NAME);
+    commands.init_resource::<SupplyCrates>();
+}
+
+/// the crates of the contract become destructible and charges can be planted on them
+fn mark_supply_crates(
+    mut commands: Commands,
+    mut accepted: EventReader<ContractAccepted>,
+    contracts: Res<Contracts>,
+    supply_crates: Option<ResMut<SupplyCrates>>,
+    crates: Query<(Entity, &ContractId, &GlobalTransform), With<SupplyCrate>>,
+) {
+    let Some(mut supply_crates) = supply_crates else {
+        return;
+    };
+    for event in accepted.read() {
+        let is_destroy_contract = contracts
+            .map
+            .get(&event.contract_id)
+            .is_some_and(|contract| contract.contract_type == ContractType::DestroySupplies);
+        if !is_destroy_contract {
+            continue;
+        }
+        let positions: Vec<Vec3> = crates
+            .iter()
+            .filter(|(_, contract_id, _)| **contract_id == event.contract_id)
+            .map(|(supply_crate, _, transform)| {
+                commands
+                    .entity(supply_crate)
+                    .insert(Interactable)
+                    .insert(HoldInteractable(PLANT_CHARGE_SECS))
+                    .insert(Health(CRATE_HEALTH))
+                    .insert(HurtBox(Aabb3d::new(
+                        Vec3::default(),
+                        Vec3::new(0.5, 0.5, 0.5),
+                    )))
+                    .insert(ContractSpotlight);
+                supply_crates.map.insert(supply_crate, event.contract_id);
+                transform.translation()
+            })
+            .collect();
+        if positions.is_empty() {
+            warn!("no supply crates for contract {:?}", event.contract_id);
+            continue;
+        }
+        debug!(
+            "{} supply crates of contract {:?} marked",
+            positions.len(),
+            event.contract_id
+        );
+        let center = positions.iter().sum::<Vec3>() / positions.len() as f32;
+        commands
+            .spawn(Transform::from_translation(center))
+            .insert(Name::new("Supplies General Area"))
+            .insert(ContractGeneralArea::default())
+            .insert(event.contract_id);
+    }
+}
+
+/// only members of the contract squad carry charges for the crates
+#[allow(clippy::type_complexity)]
+fn plant_charge(
+    mut commands: Commands,
+    mut interactions: EventReader<Interact>,
+    crates: Query<(&ContractId, &GlobalTransform), (With<SupplyCrate>, With<HoldInteractable>)>,
+    operators: Query<&SquadId, With<Operator>>,
+    squads: Res<Squads>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for interaction in interactions.read() {
+        let Ok((contract_id, transform)) = crates.get(interaction.interaction_entity) else {
+            continue;
+        };
+        let Ok(squad_id) = operators.get(interaction.operator_entity) else {
+            continue;
+        };
+        if contract_squad(&squads, *contract_id) != Some(squad_id) {
+            debug!("squad {:?} has no charges for this crate", squad_id);
+            continue;
+        }
+        debug!(
+            "charge planted on {} by {}",
+            interaction.interaction_entity, interaction.operator_entity
+        );
+        commands
+            .entity(interaction.interaction_entity)
+            .remove::<Interactable>()
+            .remove::<HoldInteractable>();
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Cuboid::new(0.2, 0.1, 0.15))),
+                MeshMaterial3d(materials.add(Color::srgb(0.8, 0.0, 0.0))),
+                Transform::from_translation(transform.translation() + Vec3::Y * 0.55),
+            ))
+            .insert(Name::new("Charge"))
+            .insert(Charge {
+                fuse: Timer::from_seconds(FUSE_SECS, TimerMode::Once),
+                planter: interaction.operator_entity,
+            });
+    }
+}
+
+/// the explosion is a hitbox dealing area damage on behalf of the planter
+fn burn_fuse(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut charges: Query<(Entity, &mut Charge, &GlobalTransform)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, mut charge, transform) in charges.iter_mut() {
+        if !charge.fuse.tick(time.delta()).just_finished() {
+            continue;
+        }
+        debug!("charge {} detonated", entity);
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Sphere::new(EXPLOSION_RADIUS))),
+                MeshMaterial3d(materials.add(Color::srgba(1.0, 0.5, 0.0, 0.5))),
+                Transform::from_translation(transform.translation()),
+            ))
+            .insert(Name::new("Explosion"))
+            .insert(Explosion(Timer::from_seconds(
+                EXPLOSION_SECS,
+                TimerMode::Once,
+            )))
+            .insert(HitBox(Aabb3d::new(
+                Vec3::default(),
+                Vec3::splat(EXPLOSION_RADIUS),
+            )))
+            .insert(Damage(EXPLOSION_DAMAGE))
+            .insert(DamageOrigin(charge.planter));
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn fade_explosion(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut explosions: Query<(Entity, &mut Explosion)>,
+) {
+    for (entity, mut explosion) in explosions.iter_mut() {
+        if explosion.0.tick(time.delta()).just_finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// every destroyed crate advances the contract, it is done once no crate of it is left
+fn supply_crate_destroyed(
+    mut died: EventReader<EntityDied>,
+    supply_crates: Option<ResMut<SupplyCrates>>,
+    mut contracts: ResMut<Contracts>,
+    mut completed: EventWriter<ContractCompleted>,
+) {
+    let Some(mut supply_crates) = supply_crates else {
+        return;
+    };
+    for event in died.read() {
+        let Some(contract_id) = supply_crates.map.remove(&event.death) else {
+            continue;
+        };
+        let Some(contract) = contracts.map.get_mut(&contract_id) else {
+            continue;
+        };
+        if let ContractState::DestroySupplies(mut state) = contract.contract_state {
+            contract.contract_state = state.next();
+            debug!(
+                "supply crate of contract {:?} destroyed: {:?}",
+                contract_id, contract.contract_state
+            );
+            if !supply_crates.map.values().any(|id| *id == contract_id) {
+                completed.send(ContractCompleted { contract_id });
+            }
+        }
+    }
+}
+
+/// no more charges for the crates of a failed or cancelled contract
+fn supplies_contract_ended(
+    mut commands: Commands,
+    mut failed: EventReader<ContractFailed>,
+    mut cancelled: EventReader<ContractCancelled>,
+    supply_crates: Option<ResMut<SupplyCrates>>,
+) {
+    let Some(mut supply_crates) = supply_crates else {
+        return;
+    };
+    let ended = ended_contracts(&mut failed, &mut cancelled);
+    supply_crates.map.retain(|supply_crate, contract_id| {
+        if !ended.contains(contract_id) {
+            return true;
+        }
+        commands
+            .entity(*supply_crate)
+            .remove::<Interactable>()
+            .remove::<HoldInteractable>()
+            .remove::<ContractSpotlight>();
+        false
+    });
+}
+
+#[allow(clippy::type_complexity)]
+fn bye_destroy_supplies(
+    mut commands: Commands,
+    entities: Query<Entity, Or<(With<Charge>, With<Explosion>)>>,
+) {
+    debug!("stopping {}", NAME);
+    commands.remove_resource::<SupplyCrates>();
+    for entity in entities.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// helper functions
+
+// tests
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::contracts::{insert_held_contract, DestroySuppliesState};
+    use crate::death::DeathKind;
+
+    fn entity_died(death: Entity) -> EntityDied {
+        EntityDied {
+            death,
+            killer: None,
+            killer_squad: None,
+            death_squad: None,
+            death_kind: DeathKind::Other,
+            death_name: None,
+            weapon: None,
+            assists: vec![],
+        }
+    }
+
+    #[test]
+    fn should_detonate_charge_after_fuse() {
+        // given
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.init_resource::<Assets<Mesh>>();
+        app.init_resource::<Assets<StandardMaterial>>();
+        app.add_systems(Update, burn_fuse);
+        let planter = app.world_mut().spawn_empty().id();
+        let charge = app
+            .world_mut()
+            .spawn((
+                Charge {
+                    fuse: Timer::from_seconds(FUSE_SECS, TimerMode::Once),
+                    planter,
+                },
+                GlobalTransform::default(),
+            ))
+            .id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(FUSE_SECS));
+        app.update();
+
+        // then
+        assert!(app.world().get_entity(charge).is_err());
+        let mut explosions = app
+            .world_mut()
+            .query_filtered::<(&Damage, &DamageOrigin), With<HitBox>>();
+        let (damage, origin) = explosions.single(app.world());
+        assert_eq!(Damage(EXPLOSION_DAMAGE), *damage);
+        assert_eq!(DamageOrigin(planter), *origin);
+    }
+
+    #[test]
+    fn should_complete_contract_when_all_crates_destroyed() {
+        // given
+        let mut app = App::new();
+        app.add_event::<EntityDied>();
+        app.add_event::<ContractCompleted>();
+        app.add_systems(Update, supply_crate_destroyed);
+        let contract_id = ContractId(13);
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::DestroySupplies,
+            ContractState::DestroySupplies(DestroySuppliesState::Started),
+        );
+        let first = app.world_mut().spawn_empty().id();
+        let second = app.world_mut().spawn_empty().id();
+        app.insert_resource(SupplyCrates {
+            map: [(first, contract_id), (second, contract_id)]
+                .into_iter()
+                .collect(),
+        });
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<EntityDied>>()
+            .send(entity_died(first));
+        app.update();
+
+        // then
+        assert_eq!(
+            ContractState::DestroySupplies(DestroySuppliesState::CratesDestroyed(1)),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        assert!(app
+            .world()
+            .resource::<Events<ContractCompleted>>()
+            .is_empty());
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<EntityDied>>()
+            .send(entity_died(second));
+        app.update();
+
+        // then
+        assert_eq!(
+            ContractState::DestroySupplies(DestroySuppliesState::CratesDestroyed(2)),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        let completed_events = app.world().resource::<Events<ContractCompleted>>();
+        let mut completed_reader = completed_events.get_cursor();
+        assert_eq!(
+            vec![&ContractCompleted { contract_id }],
+            completed_reader.read(completed_events).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,419 @@
+use std::f32::consts::TAU;
+
+use bevy::app::Plugin;
+use bevy::math::bounding::Aabb3d;
+use bevy::utils::HashMap;
+
+use crate::armor::Armor;
+use crate::contracts::{
+    contract_squad, ended_contracts, ContractAccepted, ContractCancelled, ContractCompleted,
+    ContractFailed, ContractGeneralArea, ContractId, ContractSpotlight, ContractState,
+    ContractStateMachine, ContractType, Contracts, CurrentContractObjective, EliminateHVTState,
+};
+use crate::damage::HurtBox;
+use crate::death::EntityDied;
+use crate::exfil::Operator;
+use crate::health::Health;
+use crate::interaction::Interactable;
+use crate::loot::{Loot, LootName, LootType, Price, Rarity};
+use crate::raid::Enemy;
+use crate::squad::{SquadId, Squads};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "eliminate HVT";
+const HVT_HEALTH: i32 = 300;
+const HVT_ARMOR: i32 = 200;
+const BODYGUARDS: usize = 3;
+const BODYGUARD_HEALTH: i32 = 150;
+const BODYGUARD_ARMOR: i32 = 150;
+/// bodyguards stand around the HVT in this distance
+const BODYGUARD_DISTANCE: f32 = 2.5;
+/// a squad member this close to the HVT located it
+const LOCATE_DISTANCE: f32 = 12.0;
+/// the general area is not centered on the HVT, it is only somewhere within the radius
+const GENERAL_AREA_OFFSET: Vec3 = Vec3::new(6.0, 0.0, -4.0);
+/// value of the intel the HVT carries
+const HVT_INTEL_PRICE: u32 = 1500;
+
+// Plugin
+/// contract to hunt down a high value target with its bodyguards
+pub struct EliminateHVTPlugin;
+
+impl Plugin for EliminateHVTPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_eliminate_hvt)
+            .add_systems(
+                Update,
+                (
+                    spawn_hvt,
+                    track_hvts,
+                    locate_hvt,
+                    hvt_died,
+                    hvt_contract_ended,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_eliminate_hvt);
+    }
+}
+
+// Components
+
+/// where the HVT of the contract with the same `ContractId` shows up once the contract is accepted
+#[derive(Component, Debug)]
+pub struct HVTSpawn;
+
+/// high value target of an eliminate HVT contract
+#[derive(Component, Debug)]
+pub struct HighValueTarget;
+
+#[derive(Component, Debug)]
+pub struct HVTBodyguard;
+
+// Resources
+
+/// contract and last known position of every living HVT, the body is gone once it died
+#[derive(Resource, Default)]
+struct HighValueTargets {
+    map: HashMap<Entity, (ContractId, Vec3)>,
+}
+
+// Events
+
+// Systems
+fn start_eliminate_hvt(mut commands: Commands) {
+    debug!("starting {}", NAME);
+    commands.init_resource::<HighValueTargets>();
+}
+
+/// HVT with bodyguards at the spawn of the contract, the squad is only told the general area
+fn spawn_hvt(
+    mut commands: Commands,
+    mut accepted: EventReader<ContractAccepted>,
+    contracts: Res<Contracts>,
+    spawns: Query<(Entity, &ContractId, &GlobalTransform), With<HVTSpawn>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for event in accepted.read() {
+        let is_hvt_contract = contracts
+            .map
+            .get(&event.contract_id)
+            .is_some_and(|contract| contract.contract_type == ContractType::EliminateHVT);
+        if !is_hvt_contract {
+            continue;
+        }
+        for (spawn, _, transform) in spawns
+            .iter()
+            .filter(|(_, contract_id, _)| **contract_id == event.contract_id)
+        {
+            let position = transform.translation();
+            debug!("HVT of contract {:?} at {}", event.contract_id, position);
+            commands
+                .spawn((
+                    Mesh3d(meshes.add(Capsule3d::new(0.3, 1.6))),
+                    MeshMaterial3d(materials.add(Color::srgb(0.9, 0.6, 0.0))),
+                    Transform::from_translation(position + Vec3::Y),
+                ))
+                .insert(Name::new("HVT"))
+                .insert(Enemy)
+                .insert(HighValueTarget)
+                .insert(event.contract_id)
+                .insert(Health(HVT_HEALTH))
+                .insert(Armor(HVT_ARMOR))
+                .insert(HurtBox(Aabb3d::new(
+                    Vec3::default(),
+                    Vec3::new(0.3, 1.1, 0.3),
+                )));
+            for bodyguard in bodyguard_positions(position) {
+                commands
+                    .spawn((
+                        Mesh3d(meshes.add(Capsule3d::new(0.25, 1.5))),
+                        MeshMaterial3d(materials.add(Color::srgb(0.5, 0.1, 0.1))),
+                        Transform::from_translation(bodyguard + Vec3::Y)
+                            .looking_at(position.with_y(bodyguard.y + 1.0), Vec3::Y),
+                    ))
+                    .insert(Name::new("HVT Bodyguard"))
+                    .insert(Enemy)
+                    .insert(HVTBodyguard)
+                    .insert(Health(BODYGUARD_HEALTH))
+                    .insert(Armor(BODYGUARD_ARMOR))
+                    .insert(HurtBox(Aabb3d::new(
+                        Vec3::default(),
+                        Vec3::new(0.25, 1.0, 0.25),
+                    )));
+            }
+            commands
+                .spawn(Transform::from_translation(position + GENERAL_AREA_OFFSET))
+                .insert(Name::new("HVT General Area"))
+                .insert(ContractGeneralArea::default())
+                .insert(event.contract_id);
+            commands.entity(spawn).despawn_recursive();
+        }
+    }
+}
+
+fn track_hvts(
+    hvts: Query<(Entity, &ContractId, &GlobalTransform), With<HighValueTarget>>,
+    targets: Option<ResMut<HighValueTargets>>,
+) {
+    let Some(mut targets) = targets else {
+        return;
+    };
+    for (hvt, contract_id, transform) in hvts.iter() {
+        targets
+            .map
+            .insert(hvt, (*contract_id, transform.translation()));
+    }
+}
+
+/// the HVT is spotlighted once a member of the contract squad got close to it
+fn locate_hvt(
+    mut commands: Commands,
+    mut contracts: ResMut<Contracts>,
+    squads: Res<Squads>,
+    hvts: Query<(Entity, &ContractId, &GlobalTransform), With<HighValueTarget>>,
+    operators: Query<(&SquadId, &GlobalTransform), With<Operator>>,
+) {
+    for (hvt, contract_id, hvt_transform) in hvts.iter() {
+        let Some(contract) = contracts.map.get_mut(contract_id) else {
+            continue;
+        };
+        let ContractState::EliminateHVT(mut state @ EliminateHVTState::Started) =
+            contract.contract_state
+        else {
+            continue;
+        };
+        let Some(squad_id) = contract_squad(&squads, *contract_id) else {
+            continue;
+        };
+        let located = operators.iter().any(|(id, transform)| {
+            id == squad_id
+                && transform
+                    .translation()
+                    .distance(hvt_transform.translation())
+                    <= LOCATE_DISTANCE
+        });
+        if located {
+            debug!("HVT of contract {:?} located", contract_id);
+            contract.contract_state = state.next();
+            commands
+                .entity(hvt)
+                .insert(ContractSpotlight)
+                .insert(CurrentContractObjective);
+        }
+    }
+}
+
+/// the HVT drops its intel and the contract is done, no matter who got the kill. a failed or
+/// cancelled contract stays that way.
+fn hvt_died(
+    mut commands: Commands,
+    mut died: EventReader<EntityDied>,
+    targets: Option<ResMut<HighValueTargets>>,
+    mut contracts: ResMut<Contracts>,
+    mut completed: EventWriter<ContractCompleted>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(mut targets) = targets else {
+        return;
+    };
+    for event in died.read() {
+        let Some((contract_id, position)) = targets.map.remove(&event.death) else {
+            continue;
+        };
+        debug!("HVT of contract {:?} eliminated", contract_id);
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Cuboid::new(0.3, 0.05, 0.2))),
+                MeshMaterial3d(materials.add(Color::srgb(0.9, 0.6, 0.0))),
+                Transform::from_translation(position.with_y(0.1)),
+            ))
+            .insert(Name::new("HVT Intel"))
+            .insert(Loot)
+            .insert(Interactable)
+            .insert(LootName(String::from("HVT Intel")))
+            .insert(LootType::Intel)
+            .insert(Price(HVT_INTEL_PRICE))
+            .insert(Rarity::Rare);
+        let Some(contract) = contracts.map.get_mut(&contract_id) else {
+            continue;
+        };
+        if contract.contract_state.is_over() {
+            continue;
+        }
+        contract.contract_state = ContractState::EliminateHVT(EliminateHVTState::Eliminated);
+        completed.send(ContractCompleted { contract_id });
+    }
+}
+
+/// the HVT of a failed or cancelled contract is no objective anymore
+fn hvt_contract_ended(
+    mut commands: Commands,
+    mut failed: EventReader<ContractFailed>,
+    mut cancelled: EventReader<ContractCancelled>,
+    hvts: Query<(Entity, &ContractId), With<HighValueTarget>>,
+) {
+    let ended = ended_contracts(&mut failed, &mut cancelled);
+    for (hvt, contract_id) in hvts.iter() {
+        if ended.contains(contract_id) {
+            commands
+                .entity(hvt)
+                .remove::<ContractSpotlight>()
+                .remove::<CurrentContractObjective>();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn bye_eliminate_hvt(
+    mut commands: Commands,
+    entities: Query<Entity, Or<(With<HighValueTarget>, With<HVTBodyguard>)>>,
+) {
+    debug!("stopping {}", NAME);
+    commands.remove_resource::<HighValueTargets>();
+    for entity in entities.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// helper functions
+
+fn bodyguard_positions(hvt: Vec3) -> Vec<Vec3> {
+    (0..BODYGUARDS)
+        .map(|i| {
+            let angle = TAU * i as f32 / BODYGUARDS as f32;
+            hvt + Vec3::new(angle.cos(), 0.0, angle.sin()) * BODYGUARD_DISTANCE
+        })
+        .collect()
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::contracts::insert_held_contract;
+    use crate::death::DeathKind;
+
+    #[test]
+    fn should_locate_hvt_when_squad_is_close() {
+        // given
+        let mut app = App::new();
+        let contract_id = ContractId(5);
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::EliminateHVT,
+            ContractState::EliminateHVT(EliminateHVTState::Started),
+        );
+        app.add_systems(Update, locate_hvt);
+        let hvt = app
+            .world_mut()
+            .spawn((
+                HighValueTarget,
+                contract_id,
+                GlobalTransform::from_xyz(20.0, 1.0, 0.0),
+            ))
+            .id();
+        let operator = app
+            .world_mut()
+            .spawn((
+                Operator,
+                SquadId(1),
+                GlobalTransform::from_xyz(0.0, 1.0, 0.0),
+            ))
+            .id();
+        app.world_mut().spawn((
+            Operator,
+            SquadId(2),
+            GlobalTransform::from_xyz(19.0, 1.0, 0.0),
+        ));
+
+        // when
+        app.update();
+
+        // then
+        assert!(app.world().get::<ContractSpotlight>(hvt).is_none());
+
+        // when
+        *app.world_mut()
+            .get_mut::<GlobalTransform>(operator)
+            .unwrap() = GlobalTransform::from_xyz(10.0, 1.0, 0.0);
+        app.update();
+
+        // then
+        assert!(app.world().get::<ContractSpotlight>(hvt).is_some());
+        assert_eq!(
+            ContractState::EliminateHVT(EliminateHVTState::Located),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+    }
+
+    #[test]
+    fn should_complete_contract_and_drop_intel_when_hvt_died() {
+        // given
+        let mut app = App::new();
+        let contract_id = ContractId(5);
+        app.add_event::<EntityDied>();
+        app.add_event::<ContractCompleted>();
+        app.init_resource::<Assets<Mesh>>();
+        app.init_resource::<Assets<StandardMaterial>>();
+        app.init_resource::<HighValueTargets>();
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::EliminateHVT,
+            ContractState::EliminateHVT(EliminateHVTState::Located),
+        );
+        app.add_systems(Update, (track_hvts, hvt_died).chain());
+        let hvt = app
+            .world_mut()
+            .spawn((
+                HighValueTarget,
+                contract_id,
+                GlobalTransform::from_xyz(4.0, 1.0, -3.0),
+            ))
+            .id();
+        app.update();
+        app.world_mut().despawn(hvt);
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<EntityDied>>()
+            .send(EntityDied {
+                death: hvt,
+                killer: None,
+                killer_squad: None,
+                death_squad: None,
+                death_kind: DeathKind::Enemy,
+                death_name: None,
+                weapon: None,
+                assists: vec![],
+            });
+        app.update();
+
+        // then
+        assert_eq!(
+            ContractState::EliminateHVT(EliminateHVTState::Eliminated),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        let completed_events = app.world().resource::<Events<ContractCompleted>>();
+        let mut completed_reader = completed_events.get_cursor();
+        assert_eq!(
+            vec![&ContractCompleted { contract_id }],
+            completed_reader.read(completed_events).collect::<Vec<_>>()
+        );
+        let mut intel = app
+            .world_mut()
+            .query_filtered::<&Transform, (With<Loot>, With<Rarity>)>();
+        let intel: Vec<&Transform> = intel.iter(app.world()).collect();
+        assert_eq!(1, intel.len());
+        assert_eq!(Vec3::new(4.0, 0.1, -3.0), intel[0].translation);
+    }
+}
@@ -1,14 +1,24 @@
 use std::time::Duration;
 
-use bevy::{prelude::*, utils::HashMap};
+use bevy::{math::bounding::Aabb3d, prelude::*, utils::HashMap};
 use bevy_inspector_egui::prelude::*;
 
 use crate::{
-    first_person_controller::PlayerControlled,
-    AppState::{Raid, StartScreen},
-    ButtonTargetState,
+    first_person_controller::PlayerControlled, raid::Volume, squad::SquadId, AppState::Raid,
 };
 
+// Constants
+
+/// the exfil helicopter shows up and leaves this far from the center of the AO
+const EXFIL_AO_RADIUS: f32 = 100.0;
+const EXFIL_CRUISE_HEIGHT: f32 = 40.0;
+const EXFIL_APPROACH_HEIGHT: f32 = 25.0;
+const EXFIL_HOVER_HEIGHT: f32 = 5.0;
+/// the body of the helicopter rests this far above the exfil area
+const EXFIL_LANDING_HEIGHT: f32 = 1.0;
+/// seats of an exfil helicopter unless the exfil area has `ExfilSeats`
+const EXFIL_DEFAULT_SEATS: usize = 4;
+
 // Events
 
 #[derive(Event)]
@@ -31,6 +41,12 @@ impl OriginState for ExfilCalled {
     const ORIGIN_STATE: ExfilState = ExfilState::Available;
 }
 
+/// the exfil call went through, the helicopter is on its way
+#[derive(Event)]
+pub struct ExfilAccepted {
+    pub exfil_entity: Entity,
+}
+
 #[allow(dead_code)]
 #[derive(Event)]
 pub struct ExfilEnteredAO;
@@ -59,9 +75,11 @@ pub struct ExfilTouchedDown;
 #[derive(Event)]
 pub struct ExfilBoardingHold;
 
-#[allow(dead_code)]
+/// the helicopter left the ground, whoever is on board is going to exfil
 #[derive(Event)]
-pub struct ExfilTookOff;
+pub struct ExfilTookOff {
+    pub exfil_entity: Entity,
+}
 
 #[allow(dead_code)]
 #[derive(Event)]
@@ -76,15 +94,18 @@ pub struct ExfilExitedAO {
     pub operator_entity: Entity,
 }
 
-#[allow(dead_code)]
+/// the exfil went through the whole procedure and is available again
 #[derive(Event)]
-pub struct ExfilCooledDown;
+pub struct ExfilCooledDown {
+    pub exfil_entity: Entity,
+}
 
 #[derive(Event)]
 pub struct Exfilled {
     #[allow(dead_code)] // not in use yet, can be used for starting particle system
     pub exfil_entity: Entity,
-    // TODO: put the operator in here somehow
+    #[allow(dead_code)]
+    pub operator_entity: Entity,
 }
 
 /// trigger for showing the prompt
@@ -110,7 +131,7 @@ struct Exfils {
 }
 
 /// enum for the exfil procedure state machine
-#[derive(Default, Debug, PartialEq, Clone, Copy, Reflect)]
+#[derive(Default, Debug, PartialEq, Eq, Hash, Clone, Copy, Reflect)]
 pub enum ExfilState {
     #[default]
     Available,
@@ -133,16 +154,74 @@ trait ExfilStateMachine {
     fn next(&mut self) -> ExfilState;
 }
 
+/// how long the exfil stays in each state, insert before the raid to override. put it on an
+/// exfil area to override it for this exfil only.
+#[derive(Resource, Component, Clone, Debug, Reflect, InspectorOptions)]
+#[reflect(Resource, InspectorOptions)]
+pub struct ExfilDurations {
+    pub secs: HashMap<ExfilState, f32>,
+}
+
+impl Default for ExfilDurations {
+    fn default() -> Self {
+        ExfilDurations {
+            secs: [
+                (ExfilState::Approached, 6.0),
+                (ExfilState::Descended, 3.0),
+                (ExfilState::LandingHover, 2.0),
+                (ExfilState::TouchedDown, 2.0),
+                (ExfilState::BoardingHold, 10.0),
+                (ExfilState::TookOff, 2.0),
+                (ExfilState::Climbed, 3.0),
+                (ExfilState::Cruised, 6.0),
+            ]
+            .into_iter()
+            .collect(),
+        }
+    }
+}
+
+impl ExfilDurations {
+    /// states without a configured duration take one second, `Available` waits for a call
+    fn timer(&self, state: ExfilState) -> Option<Timer> {
+        if state == ExfilState::Available {
+            return None;
+        }
+        let secs = self.secs.get(&state).copied().unwrap_or(1.0);
+        Some(Timer::new(Duration::from_secs_f32(secs), TimerMode::Once))
+    }
+}
+
 #[derive(Default, Reflect)]
 struct Exfil {
     current_state: ExfilState,
     current_timer: Option<Timer>,
     exfil_operator: Option<Entity>,
+    durations: ExfilDurations,
+    seats: usize,
+    /// only operators of this squad can board
+    private_to: Option<SquadId>,
+    /// operators inside of the boarding volume when the helicopter took off
+    boarded: Vec<Entity>,
+}
+
+impl Exfil {
+    fn new(durations: ExfilDurations, seats: usize) -> Self {
+        Exfil {
+            durations,
+            seats,
+            ..default()
+        }
+    }
+
+    /// progress within the current state
+    fn fraction(&self) -> f32 {
+        self.current_timer.as_ref().map_or(0.0, Timer::fraction)
+    }
 }
 
 impl ExfilStateMachine for Exfil {
     fn next(&mut self) -> ExfilState {
-        let default_timer = Timer::new(Duration::from_secs(1), TimerMode::Once);
         debug!("switching exfil state from {:?}", self.current_state);
         self.current_state = match self.current_state {
             ExfilState::Available => ExfilState::Called,
@@ -160,22 +239,7 @@ impl ExfilStateMachine for Exfil {
             ExfilState::ExitedAO => ExfilState::Cooldown,
             ExfilState::Cooldown => ExfilState::Available,
         };
-        self.current_timer = match self.current_state {
-            ExfilState::Available => None,
-            ExfilState::Called => Some(default_timer),
-            ExfilState::EnteredAO => Some(default_timer),
-            ExfilState::Spawned => Some(default_timer),
-            ExfilState::Approached => Some(default_timer),
-            ExfilState::Descended => Some(default_timer),
-            ExfilState::LandingHover => Some(default_timer),
-            ExfilState::TouchedDown => Some(default_timer),
-            ExfilState::BoardingHold => Some(default_timer),
-            ExfilState::TookOff => Some(default_timer),
-            ExfilState::Climbed => Some(default_timer),
-            ExfilState::Cruised => Some(default_timer),
-            ExfilState::ExitedAO => Some(default_timer),
-            ExfilState::Cooldown => Some(default_timer),
-        };
+        self.current_timer = self.durations.timer(self.current_state);
         debug!("switching exfil state to {:?}", self.current_state);
         self.current_state
     }
@@ -206,11 +270,13 @@ impl Plugin for ExfilPlugin {
                 (
                     update_exfil,
                     progress_exfils,
+                    update_exfil_helicopters,
                     exfil_created,
                     exfil_area_collision_detection,
                     exfil_area_entered,
                     exfil_area_exited,
                     exfil_called,
+                    exfil_area_removed,
                 )
                     .run_if(in_state(Raid)),
             )
@@ -218,12 +284,17 @@ impl Plugin for ExfilPlugin {
             .add_event::<ExfilAreaEntered>()
             .add_event::<ExfilAreaExited>()
             .init_resource::<Exfils>()
+            .init_resource::<ExfilDurations>()
             .register_type::<Exfils>()
+            .register_type::<ExfilDurations>()
             .register_type::<CurrentExfil>()
             .add_event::<ExfilCreated>()
             .add_event::<ExfilCalled>()
+            .add_event::<ExfilAccepted>()
             .add_event::<Exfilled>()
-            .add_event::<ExfilExitedAO>();
+            .add_event::<ExfilTookOff>()
+            .add_event::<ExfilExitedAO>()
+            .add_event::<ExfilCooledDown>();
     }
 }
 
@@ -239,6 +310,23 @@ pub struct CurrentExfil(pub Entity);
 #[derive(Component)]
 struct ExfilButton;
 
+/// seat capacity of the helicopter of this exfil area
+#[derive(Component, Debug)]
+pub struct ExfilSeats(pub usize);
+
+/// exfil area only the squad of the caller can board, it is called right away
+#[derive(Component, Debug)]
+pub struct PrivateExfil {
+    pub squad: SquadId,
+    pub caller: Entity,
+}
+
+/// helicopter picking up operators at an exfil area, its `Volume` is the boarding volume
+#[derive(Component, Debug)]
+pub struct ExfilHelicopter {
+    pub exfil: Entity,
+}
+
 // potential issues: overlapping/multiple exfils might be an issue or not
 #[derive(Component, Clone, Debug, Default)]
 pub struct InsideExfilArea(ExfilArea);
@@ -282,8 +370,7 @@ fn start_exfil(mut commands: Commands) {
                             ..default()
                         })
                         .insert(TextColor(Color::srgb(0.9, 0.9, 0.9)));
-                })
-                .insert(ButtonTargetState(StartScreen));
+                });
         })
         .id();
 
@@ -301,14 +388,33 @@ fn start_exfil(mut commands: Commands) {
         .insert(ExfilButton);
 }
 
+#[allow(clippy::type_complexity)]
 fn exfil_created(
-    query: Query<Entity, Added<ExfilArea>>,
+    query: Query<
+        (
+            Entity,
+            Option<&ExfilSeats>,
+            Option<&ExfilDurations>,
+            Option<&PrivateExfil>,
+        ),
+        Added<ExfilArea>,
+    >,
     mut exfil_spawn: EventWriter<ExfilCreated>,
     mut exfil_map: ResMut<Exfils>,
+    durations: Res<ExfilDurations>,
 ) {
-    for entity in query.iter() {
+    for (entity, seats, area_durations, private) in query.iter() {
         debug!("exfil was spawned");
-        exfil_map.map.insert(entity, Exfil::default());
+        let seats = seats.map_or(EXFIL_DEFAULT_SEATS, |seats| seats.0);
+        let durations = area_durations.unwrap_or(durations.as_ref()).clone();
+        let mut exfil = Exfil::new(durations, seats);
+        if let Some(private) = private {
+            debug!("private exfil for squad {:?}", private.squad);
+            exfil.private_to = Some(private.squad.clone());
+            exfil.exfil_operator = Some(private.caller);
+            exfil.next();
+        }
+        exfil_map.map.insert(entity, exfil);
         exfil_spawn.send(ExfilCreated {
             exfil_entity: entity,
         });
@@ -316,10 +422,16 @@ fn exfil_created(
 }
 
 /// system that progresses all timers for all current exfils
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn progress_exfils(
     mut exfils: ResMut<Exfils>,
     time: Res<Time>,
+    helicopters: Query<(&ExfilHelicopter, &GlobalTransform, &Volume)>,
+    operators: Query<(Entity, &GlobalTransform, Option<&SquadId>), With<Operator>>,
+    mut took_off: EventWriter<ExfilTookOff>,
     mut exit_ao: EventWriter<ExfilExitedAO>,
+    mut exfilled: EventWriter<Exfilled>,
+    mut cooled_down: EventWriter<ExfilCooledDown>,
 ) {
     for (entity, exfil) in exfils.map.iter_mut() {
         if let Some(timer) = &mut exfil.current_timer {
@@ -330,11 +442,27 @@ fn progress_exfils(
                 if exfil.current_state != ExfilState::Available {
                     exfil.next(); // shortcut / hack until fully implemented
                 }
+                if exfil.current_state == ExfilState::TookOff {
+                    exfil.boarded = boarded_operators(*entity, exfil, &helicopters, &operators);
+                    debug!("Exfil({:?}) took off with {:?}", entity, exfil.boarded);
+                    took_off.send(ExfilTookOff {
+                        exfil_entity: *entity,
+                    });
+                }
                 if exfil.current_state == ExfilState::ExitedAO {
-                    if let Some(operator_entity) = exfil.exfil_operator {
+                    for operator_entity in exfil.boarded.drain(..) {
                         exit_ao.send(ExfilExitedAO { operator_entity });
+                        exfilled.send(Exfilled {
+                            exfil_entity: *entity,
+                            operator_entity,
+                        });
                     }
                 }
+                if exfil.current_state == ExfilState::Available {
+                    cooled_down.send(ExfilCooledDown {
+                        exfil_entity: *entity,
+                    });
+                }
             } else if !timer.finished() {
                 debug!("Exfil({:?}) timer is at {:?}!", entity, timer);
             }
@@ -342,6 +470,52 @@ fn progress_exfils(
     }
 }
 
+/// spawns, moves and removes the helicopters according to the state of their exfil
+fn update_exfil_helicopters(
+    mut commands: Commands,
+    exfils: Res<Exfils>,
+    areas: Query<&GlobalTransform, With<ExfilArea>>,
+    mut helicopters: Query<(Entity, &ExfilHelicopter, &mut Transform)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (exfil_entity, exfil) in exfils.map.iter() {
+        let Ok(area) = areas.get(*exfil_entity) else {
+            continue;
+        };
+        let landing = area.translation() + Vec3::Y * EXFIL_LANDING_HEIGHT;
+        let position = helicopter_position(exfil.current_state, exfil.fraction(), landing);
+        let helicopter = helicopters
+            .iter_mut()
+            .find(|(_, helicopter, _)| helicopter.exfil == *exfil_entity);
+        match (position, helicopter) {
+            (Some(position), Some((_, _, mut transform))) => {
+                transform.translation = position;
+            }
+            (Some(position), None) => {
+                debug!("exfil helicopter spawned for {:?}", exfil_entity);
+                commands
+                    .spawn((
+                        Mesh3d(meshes.add(Cuboid::new(2.5, 2.0, 6.0))),
+                        MeshMaterial3d(materials.add(Color::srgb(0.2, 0.25, 0.3))),
+                        Transform::from_translation(position)
+                            .looking_at(Vec3::new(landing.x, position.y, landing.z), Vec3::Y),
+                    ))
+                    .insert(Name::new("Exfil Helicopter"))
+                    .insert(Volume(Aabb3d::new(Vec3::ZERO, Vec3::new(2.0, 2.0, 4.0))))
+                    .insert(ExfilHelicopter {
+                        exfil: *exfil_entity,
+                    });
+            }
+            (None, Some((helicopter, _, _))) => {
+                debug!("exfil helicopter of {:?} left the AO", exfil_entity);
+                commands.entity(helicopter).despawn_recursive();
+            }
+            (None, None) => {}
+        }
+    }
+}
+
 #[allow(clippy::type_complexity)]
 fn update_exfil(
     mut interaction_query: Query<
@@ -380,7 +554,11 @@ fn update_exfil(
 }
 
 /// exfil called event listener
-fn exfil_called(mut exfil_called: EventReader<ExfilCalled>, mut exfils: ResMut<Exfils>) {
+fn exfil_called(
+    mut exfil_called: EventReader<ExfilCalled>,
+    mut exfils: ResMut<Exfils>,
+    mut accepted: EventWriter<ExfilAccepted>,
+) {
     for event in exfil_called.read() {
         debug!("exfil called event received");
         let exfil = exfils.map.get_mut(&event.exfil_entity);
@@ -388,6 +566,9 @@ fn exfil_called(mut exfil_called: EventReader<ExfilCalled>, mut exfils: ResMut<E
             if e.current_state == ExfilCalled::ORIGIN_STATE {
                 e.next();
                 e.exfil_operator = Some(event.calling_entity);
+                accepted.send(ExfilAccepted {
+                    exfil_entity: event.exfil_entity,
+                });
             } else {
                 debug!("wrong origin state, event ignored");
             }
@@ -395,11 +576,26 @@ fn exfil_called(mut exfil_called: EventReader<ExfilCalled>, mut exfils: ResMut<E
     }
 }
 
-fn bye_exfil(mut commands: Commands, menu_data: Res<ExfilUIData>) {
+/// forget about exfils whose area is gone
+fn exfil_area_removed(mut removed: RemovedComponents<ExfilArea>, mut exfils: ResMut<Exfils>) {
+    for entity in removed.read() {
+        debug!("exfil area {:?} removed", entity);
+        exfils.map.remove(&entity);
+    }
+}
+
+fn bye_exfil(
+    mut commands: Commands,
+    menu_data: Res<ExfilUIData>,
+    helicopters: Query<Entity, With<ExfilHelicopter>>,
+) {
     debug!("exiting exfil called");
     commands
         .entity(menu_data.exfil_button_entity)
         .despawn_recursive();
+    for helicopter in helicopters.iter() {
+        commands.entity(helicopter).despawn_recursive();
+    }
 }
 
 fn exfil_area_entered(
@@ -494,6 +690,83 @@ fn exfil_area_collision_detection(
     }
 }
 
+// helper functions
+
+/// operators inside of the boarding volume of the helicopter of this exfil, no matter their
+/// squad unless the exfil is private. the caller gets a seat first, then whoever is closest
+/// until the seats are taken.
+#[allow(clippy::type_complexity)]
+fn boarded_operators(
+    exfil_entity: Entity,
+    exfil: &Exfil,
+    helicopters: &Query<(&ExfilHelicopter, &GlobalTransform, &Volume)>,
+    operators: &Query<(Entity, &GlobalTransform, Option<&SquadId>), With<Operator>>,
+) -> Vec<Entity> {
+    let Some((_, helicopter, volume)) = helicopters
+        .iter()
+        .find(|(helicopter, _, _)| helicopter.exfil == exfil_entity)
+    else {
+        return Vec::new();
+    };
+    let center = helicopter.translation();
+    let mut inside: Vec<(Entity, f32)> = operators
+        .iter()
+        .filter(|(_, operator, _)| volume.contains(center, operator.translation()))
+        .filter(|(_, _, squad_id)| {
+            exfil
+                .private_to
+                .as_ref()
+                .is_none_or(|squad| *squad_id == Some(squad))
+        })
+        .map(|(operator, transform, _)| (operator, transform.translation().distance(center)))
+        .collect();
+    inside.sort_by(|(a, a_distance), (b, b_distance)| {
+        let a_caller = Some(*a) == exfil.exfil_operator;
+        let b_caller = Some(*b) == exfil.exfil_operator;
+        b_caller
+            .cmp(&a_caller)
+            .then(a_distance.total_cmp(b_distance))
+    });
+    if inside.len() > exfil.seats {
+        debug!(
+            "exfil helicopter is full, left behind: {:?}",
+            &inside[exfil.seats..]
+        );
+    }
+    inside
+        .into_iter()
+        .take(exfil.seats)
+        .map(|(operator, _)| operator)
+        .collect()
+}
+
+/// where the helicopter is within the given state, it flies in from the AO edge closest to
+/// the landing point and leaves the same way. no helicopter before it spawned and after it left.
+fn helicopter_position(state: ExfilState, fraction: f32, landing: Vec3) -> Option<Vec3> {
+    let direction = landing.xz().try_normalize().unwrap_or(Vec2::X);
+    let edge =
+        Vec3::new(direction.x, 0.0, direction.y) * EXFIL_AO_RADIUS + Vec3::Y * EXFIL_CRUISE_HEIGHT;
+    let above = landing + Vec3::Y * EXFIL_APPROACH_HEIGHT;
+    let hover = landing + Vec3::Y * EXFIL_HOVER_HEIGHT;
+    let (from, to) = match state {
+        ExfilState::Available
+        | ExfilState::Called
+        | ExfilState::EnteredAO
+        | ExfilState::ExitedAO
+        | ExfilState::Cooldown => return None,
+        ExfilState::Spawned => (edge, edge),
+        ExfilState::Approached => (edge, above),
+        ExfilState::Descended => (above, hover),
+        ExfilState::LandingHover => (hover, hover),
+        ExfilState::TouchedDown => (hover, landing),
+        ExfilState::BoardingHold => (landing, landing),
+        ExfilState::TookOff => (landing, hover),
+        ExfilState::Climbed => (hover, above),
+        ExfilState::Cruised => (above, edge),
+    };
+    Some(from.lerp(to, fraction.clamp(0.0, 1.0)))
+}
+
 // tests
 #[cfg(test)]
 mod tests {
@@ -540,4 +813,144 @@ mod tests {
         //assert!(app.world.get::<Health>(entity).is_some());
         //assert_eq!(app.world.get::<Health>(entity).unwrap().0, 90);
     }
+
+    #[test]
+    fn should_follow_exfil_states_with_helicopter() {
+        // given
+        let landing = Vec3::new(10.0, 1.0, 0.0);
+        let edge = Vec3::new(EXFIL_AO_RADIUS, EXFIL_CRUISE_HEIGHT, 0.0);
+
+        // then
+        assert_eq!(None, helicopter_position(ExfilState::Called, 0.5, landing));
+        assert_eq!(
+            Some(edge),
+            helicopter_position(ExfilState::Spawned, 0.5, landing)
+        );
+        assert_eq!(
+            Some(landing + Vec3::Y * EXFIL_HOVER_HEIGHT),
+            helicopter_position(ExfilState::Descended, 1.0, landing)
+        );
+        assert_eq!(
+            Some(landing),
+            helicopter_position(ExfilState::BoardingHold, 0.5, landing)
+        );
+        assert_eq!(
+            Some(edge),
+            helicopter_position(ExfilState::Cruised, 1.0, landing)
+        );
+        assert_eq!(
+            None,
+            helicopter_position(ExfilState::ExitedAO, 0.0, landing)
+        );
+    }
+
+    #[test]
+    fn should_only_exfil_operators_inside_boarding_volume() {
+        // given
+        let mut app = App::new();
+        app.add_event::<ExfilTookOff>();
+        app.add_event::<ExfilExitedAO>();
+        app.add_event::<Exfilled>();
+        app.add_event::<ExfilCooledDown>();
+        app.init_resource::<Time>();
+        app.add_systems(Update, progress_exfils);
+        let exfil_entity = app.world_mut().spawn_empty().id();
+        app.world_mut().spawn((
+            ExfilHelicopter {
+                exfil: exfil_entity,
+            },
+            GlobalTransform::from_xyz(10.0, 1.0, 0.0),
+            Volume(Aabb3d::new(Vec3::ZERO, Vec3::new(2.0, 2.0, 4.0))),
+        ));
+        let boarded = app
+            .world_mut()
+            .spawn((Operator, GlobalTransform::from_xyz(10.0, 0.0, 1.0)))
+            .id();
+        app.world_mut()
+            .spawn((Operator, GlobalTransform::from_xyz(20.0, 0.0, 0.0)));
+        let durations = ExfilDurations {
+            secs: [(ExfilState::BoardingHold, 1.0)].into_iter().collect(),
+        };
+        let mut exfil = Exfil::new(durations, EXFIL_DEFAULT_SEATS);
+        exfil.current_state = ExfilState::BoardingHold;
+        exfil.current_timer = exfil.durations.timer(ExfilState::BoardingHold);
+        let mut exfils = Exfils::default();
+        exfils.map.insert(exfil_entity, exfil);
+        app.insert_resource(exfils);
+
+        // when
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        app.update();
+
+        // then
+        let exfils = app.world().resource::<Exfils>();
+        assert_eq!(ExfilState::TookOff, exfils.map[&exfil_entity].current_state);
+        assert_eq!(vec![boarded], exfils.map[&exfil_entity].boarded);
+    }
+
+    #[test]
+    fn should_leave_operators_behind_when_seats_are_taken() {
+        // given
+        let mut app = App::new();
+        app.add_event::<ExfilTookOff>();
+        app.add_event::<ExfilExitedAO>();
+        app.add_event::<Exfilled>();
+        app.add_event::<ExfilCooledDown>();
+        app.init_resource::<Time>();
+        app.add_systems(Update, progress_exfils);
+        let exfil_entity = app.world_mut().spawn_empty().id();
+        app.world_mut().spawn((
+            ExfilHelicopter {
+                exfil: exfil_entity,
+            },
+            GlobalTransform::from_xyz(0.0, 1.0, 0.0),
+            Volume(Aabb3d::new(Vec3::ZERO, Vec3::new(2.0, 2.0, 4.0))),
+        ));
+        let caller = app
+            .world_mut()
+            .spawn((Operator, GlobalTransform::from_xyz(0.0, 0.0, 3.0)))
+            .id();
+        let closest = app
+            .world_mut()
+            .spawn((Operator, GlobalTransform::from_xyz(0.0, 0.0, 1.0)))
+            .id();
+        app.world_mut()
+            .spawn((Operator, GlobalTransform::from_xyz(0.0, 0.0, 2.0)));
+        let durations = ExfilDurations {
+            secs: [(ExfilState::Cruised, 1.0)].into_iter().collect(),
+        };
+        let mut exfil = Exfil::new(durations, 2);
+        exfil.current_state = ExfilState::BoardingHold;
+        exfil.current_timer = exfil.durations.timer(ExfilState::BoardingHold);
+        exfil.exfil_operator = Some(caller);
+        let mut exfils = Exfils::default();
+        exfils.map.insert(exfil_entity, exfil);
+        app.insert_resource(exfils);
+
+        // when
+        for _ in 0..4 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            app.update();
+        }
+
+        // then
+        let exfils = app.world().resource::<Exfils>();
+        assert_eq!(
+            ExfilState::ExitedAO,
+            exfils.map[&exfil_entity].current_state
+        );
+        let exfilled_events = app.world().resource::<Events<Exfilled>>();
+        let mut exfilled_reader = exfilled_events.get_cursor();
+        assert_eq!(
+            vec![caller, closest],
+            exfilled_reader
+                .read(exfilled_events)
+                .map(|exfilled| exfilled.operator_entity)
+                .collect::<Vec<_>>()
+        );
+    }
 }
@@ -0,0 +1,269 @@
+use std::f32::consts::TAU;
+
+use bevy::app::Plugin;
+use bevy::math::bounding::Aabb3d;
+use bevy::utils::HashMap;
+use bevy_inspector_egui::prelude::*;
+
+use crate::damage::HurtBox;
+use crate::exfil::{ExfilAccepted, ExfilArea, ExfilTookOff};
+use crate::health::Health;
+use crate::raid::Enemy;
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "exfil hot zone";
+const WAVE_INTERVAL_SECS: f32 = 8.0;
+/// enemies per wave before the threat level is added
+const WAVE_BASE_SIZE: usize = 2;
+/// distance from the exfil area where the waves spawn
+const WAVE_SPAWN_RADIUS: f32 = 25.0;
+const ATTACKER_SPEED: f32 = 2.5;
+/// attackers stop pushing once they are this close to the exfil area
+const ATTACKER_HOLD_DISTANCE: f32 = 3.0;
+
+// Plugin
+/// enemy waves pushing the landing zone of an accepted exfil call until the helicopter took off
+pub struct ExfilHotZonePlugin;
+
+impl Plugin for ExfilHotZonePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ThreatLevel>()
+            .register_type::<ThreatLevel>()
+            .add_systems(OnEnter(Raid), start_exfil_hot_zone)
+            .add_systems(
+                Update,
+                (
+                    exfil_accepted,
+                    exfil_took_off,
+                    spawn_waves,
+                    push_landing_zone,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_exfil_hot_zone);
+    }
+}
+
+// Components
+
+/// enemy of an exfil hot zone wave, moves toward the exfil area
+#[derive(Component, Debug)]
+pub struct HotZoneAttacker {
+    pub exfil: Entity,
+}
+
+// Resources
+
+/// scales the size of the exfil waves, insert before the raid to override
+#[derive(Resource, Reflect, InspectorOptions)]
+#[reflect(Resource, InspectorOptions)]
+pub struct ThreatLevel(pub u32);
+
+impl Default for ThreatLevel {
+    fn default() -> Self {
+        ThreatLevel(1)
+    }
+}
+
+struct HotZone {
+    wave_timer: Timer,
+    waves: u32,
+}
+
+/// exfils that are currently under attack
+#[derive(Resource, Default)]
+struct HotZones {
+    map: HashMap<Entity, HotZone>,
+}
+
+// Events
+
+// Systems
+fn start_exfil_hot_zone(mut commands: Commands) {
+    debug!("starting {}", NAME);
+    commands.init_resource::<HotZones>();
+}
+
+/// only calls the exfil accepted become a hot zone, calling a busy exfil does nothing
+fn exfil_accepted(mut accepted: EventReader<ExfilAccepted>, hot_zones: Option<ResMut<HotZones>>) {
+    let Some(mut hot_zones) = hot_zones else {
+        return;
+    };
+    for event in accepted.read() {
+        debug!("exfil {} is a hot zone now", event.exfil_entity);
+        hot_zones.map.entry(event.exfil_entity).or_insert(HotZone {
+            wave_timer: Timer::from_seconds(WAVE_INTERVAL_SECS, TimerMode::Repeating),
+            waves: 0,
+        });
+    }
+}
+
+/// no more waves once the helicopter is in the air
+fn exfil_took_off(mut took_off: EventReader<ExfilTookOff>, hot_zones: Option<ResMut<HotZones>>) {
+    let Some(mut hot_zones) = hot_zones else {
+        return;
+    };
+    for event in took_off.read() {
+        if hot_zones.map.remove(&event.exfil_entity).is_some() {
+            debug!("exfil {} took off, hot zone is over", event.exfil_entity);
+        }
+    }
+}
+
+fn spawn_waves(
+    mut commands: Commands,
+    time: Res<Time>,
+    threat_level: Res<ThreatLevel>,
+    hot_zones: Option<ResMut<HotZones>>,
+    areas: Query<&GlobalTransform, With<ExfilArea>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(mut hot_zones) = hot_zones else {
+        return;
+    };
+    for (exfil, hot_zone) in hot_zones.map.iter_mut() {
+        // the first wave comes right away
+        let wave_due =
+            hot_zone.waves == 0 || hot_zone.wave_timer.tick(time.delta()).just_finished();
+        if !wave_due {
+            continue;
+        }
+        let Ok(area) = areas.get(*exfil) else {
+            continue;
+        };
+        let size = wave_size(threat_level.0);
+        debug!(
+            "wave {} of {} enemies for exfil {}",
+            hot_zone.waves, size, exfil
+        );
+        for position in wave_positions(area.translation(), size, hot_zone.waves) {
+            commands
+                .spawn((
+                    Mesh3d(meshes.add(Capsule3d::new(0.25, 1.5))),
+                    MeshMaterial3d(materials.add(Color::srgb(0.75, 0.0, 0.0))),
+                    Transform::from_translation(position + Vec3::Y)
+                        .with_scale(Vec3::new(1.0, 1.0, 0.5)),
+                ))
+                .insert(Enemy)
+                .insert(Name::new("Exfil Attacker"))
+                .insert(HotZoneAttacker { exfil: *exfil })
+                .insert(Health::default())
+                .insert(HurtBox(Aabb3d::new(
+                    Vec3::default(),
+                    Vec3::new(0.25, 1.0, 0.25),
+                )));
+        }
+        hot_zone.waves += 1;
+    }
+}
+
+/// attackers run toward the exfil area and hold position close to it
+fn push_landing_zone(
+    time: Res<Time>,
+    areas: Query<&GlobalTransform, With<ExfilArea>>,
+    mut attackers: Query<(&HotZoneAttacker, &mut Transform)>,
+) {
+    for (attacker, mut transform) in attackers.iter_mut() {
+        let Ok(area) = areas.get(attacker.exfil) else {
+            continue;
+        };
+        let difference = (area.translation() - transform.translation).with_y(0.0);
+        if difference.length() <= ATTACKER_HOLD_DISTANCE {
+            continue;
+        }
+        let direction = difference.normalize();
+        transform.translation += direction * ATTACKER_SPEED * time.delta_secs();
+        transform.look_to(direction, Vec3::Y);
+    }
+}
+
+fn bye_exfil_hot_zone(mut commands: Commands, attackers: Query<Entity, With<HotZoneAttacker>>) {
+    debug!("stopping {}", NAME);
+    commands.remove_resource::<HotZones>();
+    for attacker in attackers.iter() {
+        commands.entity(attacker).despawn_recursive();
+    }
+}
+
+// helper functions
+
+fn wave_size(threat_level: u32) -> usize {
+    WAVE_BASE_SIZE + threat_level as usize
+}
+
+/// evenly spread around the exfil area, every wave comes in from a slightly different angle
+fn wave_positions(center: Vec3, size: usize, wave: u32) -> Vec<Vec3> {
+    let offset = wave as f32 * 0.5;
+    (0..size)
+        .map(|i| {
+            let angle = offset + TAU * i as f32 / size as f32;
+            center + Vec3::new(angle.cos(), 0.0, angle.sin()) * WAVE_SPAWN_RADIUS
+        })
+        .collect()
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn should_scale_waves_with_threat_level() {
+        assert_eq!(3, wave_size(1));
+        assert_eq!(5, wave_size(3));
+    }
+
+    #[test]
+    fn should_spawn_waves_around_exfil_area() {
+        // given
+        let center = Vec3::new(2.0, 0.5, 2.0);
+
+        // when
+        let positions = wave_positions(center, 4, 0);
+
+        // then
+        assert_eq!(4, positions.len());
+        for position in positions {
+            assert!((position.distance(center) - WAVE_SPAWN_RADIUS).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn should_stop_hot_zone_when_exfil_took_off() {
+        // given
+        let mut app = App::new();
+        app.add_event::<ExfilAccepted>();
+        app.add_event::<ExfilTookOff>();
+        app.init_resource::<HotZones>();
+        app.add_systems(Update, (exfil_accepted, exfil_took_off).chain());
+        let exfil_entity = app.world_mut().spawn_empty().id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<ExfilAccepted>>()
+            .send(ExfilAccepted { exfil_entity });
+        app.update();
+
+        // then
+        assert!(app
+            .world()
+            .resource::<HotZones>()
+            .map
+            .contains_key(&exfil_entity));
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<ExfilTookOff>>()
+            .send(ExfilTookOff { exfil_entity });
+        app.update();
+
+        // then
+        assert!(app.world().resource::<HotZones>().map.is_empty());
+    }
+}
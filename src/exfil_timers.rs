@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
 
 use bevy::app::{App, Plugin};
@@ -17,6 +19,11 @@ impl RaidCountdown {
             TimerMode::Once,
         ))
     }
+
+    /// time left until the gas starts spreading
+    pub fn remaining(&self) -> Duration {
+        self.0.remaining()
+    }
 }
 
 // We need to implement Default so that our timer can be initialized as
@@ -37,6 +44,11 @@ impl GasSpreadingCountdown {
             TimerMode::Once,
         ))
     }
+
+    /// progress of the spreading gas, 0.0 at start and 1.0 when the AO is fully covered
+    pub fn fraction(&self) -> f32 {
+        self.0.fraction()
+    }
 }
 
 // We need to implement Default so that our timer can be initialized as
@@ -47,11 +59,16 @@ impl Default for GasSpreadingCountdown {
     }
 }
 
+/// the gas covers the whole AO, the raid is over
+#[derive(Event, Debug, PartialEq)]
+pub struct GasSpreadingFinished;
+
 pub struct ExfilTimersPlugin;
 
 impl Plugin for ExfilTimersPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(Raid), start_exfil_timers)
+        app.add_event::<GasSpreadingFinished>()
+            .add_systems(OnEnter(Raid), start_exfil_timers)
             .add_systems(FixedUpdate, (update_raid_countdown).run_if(in_state(Raid)))
             .add_systems(
                 FixedUpdate,
@@ -86,6 +103,7 @@ fn update_raid_countdown(
 fn update_gas_spreading_countdown(
     time: Res<Time>,
     match_time: Option<ResMut<GasSpreadingCountdown>>,
+    mut finished: EventWriter<GasSpreadingFinished>,
 ) {
     debug!("updating exfil timer called(GasSpreadingCountdown)");
     if let Some(mut gas_spreading) = match_time {
@@ -96,6 +114,7 @@ fn update_gas_spreading_countdown(
                 "GasSpreadingCountdown finished: {:?}",
                 gas_spreading.0.remaining()
             );
+            finished.send(GasSpreadingFinished);
         }
     }
 }
@@ -1,11 +1,14 @@
 use crate::armor::Armor;
 use crate::backpack_summary::BackpackSummary;
-use crate::contracts::{ContractId, ContractPhone, ContractType};
+use crate::cargo_delivery::{CargoPickup, DeliveryZone};
+use crate::contracts::{ContractId, ContractPhone, ContractTimeLimit, ContractType};
 use crate::coordinates::{GridOffset, GridScale};
 // TODO: how to make sure every operator has a backpack attached to it
 //  TODO: transfer from the active loadout screen should be done
 //  * transfer from state from one appstate to another: active dute layout -> ...load in -> raid
-use crate::damage::{Damage, DamageOrigin, HitBox, HurtBox};
+use crate::damage::{Damage, HitBox, HurtBox};
+use crate::destroy_supplies::SupplyCrate;
+use crate::eliminate_hvt::HVTSpawn;
 use crate::exfil::{ExfilArea, Operator};
 use crate::first_person_controller::PlayerControlled;
 use crate::flee::Ghost;
@@ -17,10 +20,16 @@ use crate::lock::{Key, Lock};
 use crate::loot::{
     Durability, ItemType, Loot, LootCacheState, LootName, LootType, Price, Rarity, Stackable,
 };
-use crate::projectile::{
-    Projectile, ProjectileEmitter, ProjectileOrigin, ProjectileTime, ProjectileVelocity,
-};
-use crate::raid::Enemy;
+use crate::personal_exfil::PersonalExfil;
+use crate::point_of_interest::PointOfInterest;
+use crate::projectile::{Projectile, ProjectileEmitter, ProjectileTime, ProjectileVelocity};
+use crate::radiation::{RadiationShielding, RadiationZone};
+use crate::raid::{Enemy, Volume};
+use crate::raid_weapon_stash::{StashNoteCache, WeaponStashSpawn};
+use crate::rescue_hostage::{HostageSpawn, RescueZone};
+use crate::secure_intel::IntelCache;
+use crate::secure_nuclear_materials::{NuclearContainerSpawn, NuclearDropOff};
+use crate::signal_intelligence::Uplink;
 use crate::spawn::{Formation, Spawn, SpawnId, SpawnPosition};
 use crate::squad::SquadId;
 use crate::wallet::Money;
@@ -46,7 +55,6 @@ impl Plugin for FakeLevelPlugin {
                     add_weapon_to_operators,
                     add_inventory_to_operators,
                     add_cubes_to_projectiles,
-                    add_squad_id_to_damage,
                     add_squad_id_to_my_operator,
                     manage_cursor,
                 )
@@ -523,7 +531,7 @@ pub fn start_fake_level(
         .insert(Loot)
         .insert(Interactable)
         .insert(LootName(String::from("Durable Gaskmask")))
-        .insert(LootType::CombatDefense)
+        .insert(LootType::CircleDefense)
         .insert(Rarity::Rare)
         .insert(FakeLevelStuff)
         .insert(Durability {
@@ -573,6 +581,103 @@ pub fn start_fake_level(
         })
         .insert(FakeLevelStuff);
 
+    // radiation zone
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(8.0, 4.0, 8.0))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgba(0.4, 0.9, 0.2, 0.2),
+                alpha_mode: AlphaMode::Blend,
+                cull_mode: None,
+                ..Default::default()
+            })),
+            Transform::from_xyz(-12.0, 2.0, 8.0),
+        ))
+        .insert(Name::new("Radiation Zone"))
+        .insert(Volume(Aabb3d::new(Vec3::ZERO, Vec3::new(4.0, 2.0, 4.0))))
+        .insert(RadiationZone { intensity: 2 })
+        .insert(FakeLevelStuff);
+
+    // points of interest
+    commands
+        .spawn(Transform::from_xyz(-12.0, 0.0, 8.0))
+        .insert(Name::new("POI Hazard Lab"))
+        .insert(PointOfInterest {
+            name: String::from("Hazard Lab"),
+            radius: 6.0,
+        })
+        .insert(FakeLevelStuff);
+    commands
+        .spawn(Transform::from_xyz(3.0, 0.0, 4.0))
+        .insert(Name::new("POI Landing Zone"))
+        .insert(PointOfInterest {
+            name: String::from("Landing Zone"),
+            radius: 4.0,
+        })
+        .insert(FakeLevelStuff);
+
+    // radiation meds
+    commands
+        .spawn((
+            Mesh3d(loot_cube.clone()),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.4, 0.9, 0.2),
+                base_color_texture: Some(texture_06.clone()),
+                uv_transform: Affine2::from_scale(Vec2::new(loot_cube_size, loot_cube_size)),
+                ..Default::default()
+            })),
+            Transform::from_xyz(1.0, 0.1, -2.0),
+        ))
+        .insert(Name::new("Loot5"))
+        .insert(Loot)
+        .insert(Interactable)
+        .insert(LootName(String::from("Rad Meds")))
+        .insert(LootType::RadiationProtection)
+        .insert(Rarity::Rare)
+        .insert(FakeLevelStuff);
+
+    // radiation suit
+    commands
+        .spawn((
+            Mesh3d(loot_cube.clone()),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.9, 0.9, 0.2),
+                base_color_texture: Some(texture_06.clone()),
+                uv_transform: Affine2::from_scale(Vec2::new(loot_cube_size, loot_cube_size)),
+                ..Default::default()
+            })),
+            Transform::from_xyz(0.0, 0.1, -2.0),
+        ))
+        .insert(Name::new("Loot6"))
+        .insert(Loot)
+        .insert(Interactable)
+        .insert(LootName(String::from("Radiation Suit")))
+        .insert(LootType::Item(ItemType::Equipment))
+        .insert(RadiationShielding(0.5))
+        .insert(Rarity::Rare)
+        .insert(FakeLevelStuff);
+
+    // personal exfil
+    commands
+        .spawn((
+            Mesh3d(loot_cube.clone()),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.2, 0.6, 0.9),
+                base_color_texture: Some(texture_06.clone()),
+                uv_transform: Affine2::from_scale(Vec2::new(loot_cube_size, loot_cube_size)),
+                ..Default::default()
+            })),
+            Transform::from_xyz(-1.0, 0.1, -2.0),
+        ))
+        .insert(Name::new("Loot7"))
+        .insert(Loot)
+        .insert(Interactable)
+        .insert(LootName(String::from("Personal Exfil")))
+        .insert(LootType::CircleDefense)
+        .insert(PersonalExfil)
+        .insert(Rarity::Rare)
+        .insert(FakeLevelStuff);
+
     // sell station
     commands
         .spawn((
@@ -763,6 +868,372 @@ pub fn start_fake_level(
         .insert(ContractType::SecureSupplies)
         .insert(FakeLevelStuff);
 
+    // eliminate HVT contract phone
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.05, 0.15, 0.01))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(1.0, 0.5, 0.0),
+                base_color_texture: Some(texture_06.clone()),
+                ..Default::default()
+            })),
+            Transform::from_xyz(16.0, 1.35, 4.0),
+        ))
+        .insert(Name::new("HVTContractPhone"))
+        .insert(Interactable)
+        .insert(ContractPhone)
+        .insert(ContractId(124))
+        .insert(ContractType::EliminateHVT)
+        .insert(FakeLevelStuff);
+
+    // secure intel contract phone
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.05, 0.15, 0.01))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.0, 1.0, 1.0),
+                base_color_texture: Some(texture_06.clone()),
+                ..Default::default()
+            })),
+            Transform::from_xyz(16.0, 1.35, 6.0),
+        ))
+        .insert(Name::new("IntelContractPhone"))
+        .insert(Interactable)
+        .insert(ContractPhone)
+        .insert(ContractId(125))
+        .insert(ContractType::SecureIntel)
+        .insert(FakeLevelStuff);
+
+    // intel caches, the intel is hidden in one of them
+    for (i, position) in [
+        Vec3::new(-20.0, 0.25, -12.0),
+        Vec3::new(-24.0, 0.25, -8.0),
+        Vec3::new(-18.0, 0.25, -4.0),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Cuboid::new(0.8, 0.5, 0.5))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::srgb(0.3, 0.3, 0.3),
+                    base_color_texture: Some(texture_06.clone()),
+                    ..Default::default()
+                })),
+                Transform::from_translation(position),
+            ))
+            .insert(Name::new(format!("IntelCache{}", i + 1)))
+            .insert(Inventory)
+            .insert(ItemSlots(4))
+            .insert(Interactable)
+            .insert(IntelCache)
+            .insert(ContractId(125))
+            .insert(FakeLevelStuff);
+    }
+
+    // HVT spawn
+    commands
+        .spawn(Transform::from_xyz(-25.0, 0.0, 30.0))
+        .insert(Name::new("HVTSpawn"))
+        .insert(HVTSpawn)
+        .insert(ContractId(124))
+        .insert(FakeLevelStuff);
+
+    // rescue hostage contract phone
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.05, 0.15, 0.01))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(1.0, 1.0, 0.0),
+                base_color_texture: Some(texture_06.clone()),
+                ..Default::default()
+            })),
+            Transform::from_xyz(16.0, 1.35, 8.0),
+        ))
+        .insert(Name::new("HostageContractPhone"))
+        .insert(Interactable)
+        .insert(ContractPhone)
+        .insert(ContractId(126))
+        .insert(ContractType::RescueHostage)
+        .insert(FakeLevelStuff);
+
+    // building the hostage is held in, open to the south
+    for (i, (size, position)) in [
+        (Vec3::new(8.0, 3.0, 0.2), Vec3::new(30.0, 1.5, -34.0)),
+        (Vec3::new(0.2, 3.0, 8.0), Vec3::new(26.0, 1.5, -30.0)),
+        (Vec3::new(0.2, 3.0, 8.0), Vec3::new(34.0, 1.5, -30.0)),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Cuboid::from_size(size))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::srgb(0.6, 0.5, 0.4),
+                    base_color_texture: Some(texture_06.clone()),
+                    ..Default::default()
+                })),
+                Transform::from_translation(position),
+            ))
+            .insert(Name::new(format!("HostageBuildingWall{}", i + 1)))
+            .insert(FakeLevelStuff);
+    }
+
+    // hostage spawn
+    commands
+        .spawn(Transform::from_xyz(30.0, 0.0, -31.0))
+        .insert(Name::new("HostageSpawn"))
+        .insert(HostageSpawn)
+        .insert(ContractId(126))
+        .insert(FakeLevelStuff);
+
+    // rescue zone
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(6.0, 0.05, 6.0))),
+            MeshMaterial3d(materials.add(Color::srgba(0.0, 1.0, 0.0, 0.3))),
+            Transform::from_xyz(10.0, 0.025, -20.0),
+        ))
+        .insert(Name::new("Rescue Zone"))
+        .insert(Volume(Aabb3d::new(Vec3::ZERO, Vec3::new(3.0, 2.0, 3.0))))
+        .insert(RescueZone)
+        .insert(FakeLevelStuff);
+
+    // destroy supplies contract phone
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.05, 0.15, 0.01))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(1.0, 0.5, 0.0),
+                base_color_texture: Some(texture_06.clone()),
+                ..Default::default()
+            })),
+            Transform::from_xyz(16.0, 1.35, 10.0),
+        ))
+        .insert(Name::new("DestroySuppliesContractPhone"))
+        .insert(Interactable)
+        .insert(ContractPhone)
+        .insert(ContractId(127))
+        .insert(ContractType::DestroySupplies)
+        .insert(FakeLevelStuff);
+
+    // supply crates to blow up
+    for (i, position) in [
+        Vec3::new(35.0, 0.5, 10.0),
+        Vec3::new(38.0, 0.5, 14.0),
+        Vec3::new(33.0, 0.5, 17.0),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::srgb(0.4, 0.5, 0.2),
+                    base_color_texture: Some(texture_06.clone()),
+                    ..Default::default()
+                })),
+                Transform::from_translation(position),
+            ))
+            .insert(Name::new(format!("SupplyCrate{}", i + 1)))
+            .insert(SupplyCrate)
+            .insert(ContractId(127))
+            .insert(FakeLevelStuff);
+    }
+
+    // raid weapon stash contract phone
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.05, 0.15, 0.01))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.5, 0.0, 1.0),
+                base_color_texture: Some(texture_06.clone()),
+                ..Default::default()
+            })),
+            Transform::from_xyz(16.0, 1.35, 12.0),
+        ))
+        .insert(Name::new("WeaponStashContractPhone"))
+        .insert(Interactable)
+        .insert(ContractPhone)
+        .insert(ContractId(128))
+        .insert(ContractType::RaidWeaponStash)
+        .insert(FakeLevelStuff);
+
+    // weapon stash spawn
+    commands
+        .spawn(Transform::from_xyz(-30.0, 0.0, -25.0))
+        .insert(Name::new("WeaponStashSpawn"))
+        .insert(WeaponStashSpawn)
+        .insert(ContractId(128))
+        .insert(FakeLevelStuff);
+
+    // caches the note with the stash code might be in
+    for (i, position) in [Vec3::new(-26.0, 0.25, -22.0), Vec3::new(-34.0, 0.25, -20.0)]
+        .into_iter()
+        .enumerate()
+    {
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Cuboid::new(0.6, 0.5, 0.4))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::srgb(0.5, 0.4, 0.3),
+                    base_color_texture: Some(texture_06.clone()),
+                    ..Default::default()
+                })),
+                Transform::from_translation(position),
+            ))
+            .insert(Name::new(format!("StashNoteCache{}", i + 1)))
+            .insert(Inventory)
+            .insert(ItemSlots(4))
+            .insert(Interactable)
+            .insert(StashNoteCache)
+            .insert(ContractId(128))
+            .insert(FakeLevelStuff);
+    }
+
+    // signal intelligence contract phone
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.05, 0.15, 0.01))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.0, 0.5, 1.0),
+                base_color_texture: Some(texture_06.clone()),
+                ..Default::default()
+            })),
+            Transform::from_xyz(16.0, 1.35, 14.0),
+        ))
+        .insert(Name::new("SignalIntelligenceContractPhone"))
+        .insert(Interactable)
+        .insert(ContractPhone)
+        .insert(ContractId(129))
+        .insert(ContractType::SignalIntelligence)
+        .insert(FakeLevelStuff);
+
+    // uplink stations
+    for (i, position) in [
+        Vec3::new(40.0, 1.0, -10.0),
+        Vec3::new(-40.0, 1.0, 10.0),
+        Vec3::new(0.0, 1.0, 45.0),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Cuboid::new(0.6, 2.0, 0.6))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::srgb(0.6, 0.6, 0.7),
+                    base_color_texture: Some(texture_06.clone()),
+                    ..Default::default()
+                })),
+                Transform::from_translation(position),
+            ))
+            .insert(Name::new(format!("Uplink{}", i + 1)))
+            .insert(Uplink)
+            .insert(ContractId(129))
+            .insert(FakeLevelStuff);
+    }
+
+    // hunt squad contract phone
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.05, 0.15, 0.01))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.5, 0.0, 0.0),
+                base_color_texture: Some(texture_06.clone()),
+                ..Default::default()
+            })),
+            Transform::from_xyz(16.0, 1.35, 16.0),
+        ))
+        .insert(Name::new("HuntSquadContractPhone"))
+        .insert(Interactable)
+        .insert(ContractPhone)
+        .insert(ContractId(130))
+        .insert(ContractType::HuntSquad)
+        .insert(FakeLevelStuff);
+
+    // cargo delivery contract phone
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.05, 0.15, 0.01))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.5, 0.0, 0.0),
+                base_color_texture: Some(texture_06.clone()),
+                ..Default::default()
+            })),
+            Transform::from_xyz(16.0, 1.35, 18.0),
+        ))
+        .insert(Name::new("CargoDeliveryContractPhone"))
+        .insert(Interactable)
+        .insert(ContractPhone)
+        .insert(ContractId(131))
+        .insert(ContractType::CargoDelivery)
+        .insert(ContractTimeLimit(300.0))
+        .insert(FakeLevelStuff);
+
+    // cargo pickup
+    commands
+        .spawn(Transform::from_xyz(24.0, 0.0, 24.0))
+        .insert(Name::new("CargoPickup"))
+        .insert(CargoPickup)
+        .insert(ContractId(131))
+        .insert(FakeLevelStuff);
+
+    // delivery zone
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(8.0, 0.05, 8.0))),
+            MeshMaterial3d(materials.add(Color::srgba(1.0, 0.6, 0.0, 0.3))),
+            Transform::from_xyz(-30.0, 0.025, 40.0),
+        ))
+        .insert(Name::new("Delivery Zone"))
+        .insert(Volume(Aabb3d::new(Vec3::ZERO, Vec3::new(4.0, 2.0, 4.0))))
+        .insert(DeliveryZone)
+        .insert(ContractId(131))
+        .insert(FakeLevelStuff);
+
+    // secure nuclear materials contract phone
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.05, 0.15, 0.01))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.5, 0.0, 0.0),
+                base_color_texture: Some(texture_06.clone()),
+                ..Default::default()
+            })),
+            Transform::from_xyz(16.0, 1.35, 20.0),
+        ))
+        .insert(Name::new("SecureNuclearMaterialsContractPhone"))
+        .insert(Interactable)
+        .insert(ContractPhone)
+        .insert(ContractId(132))
+        .insert(ContractType::SecureNuclearMaterials)
+        .insert(FakeLevelStuff);
+
+    // nuclear container
+    commands
+        .spawn(Transform::from_xyz(-40.0, 0.0, -40.0))
+        .insert(Name::new("NuclearContainerSpawn"))
+        .insert(NuclearContainerSpawn)
+        .insert(ContractId(132))
+        .insert(FakeLevelStuff);
+
+    // nuclear drop-off
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(6.0, 0.05, 6.0))),
+            MeshMaterial3d(materials.add(Color::srgba(0.8, 0.7, 0.1, 0.3))),
+            Transform::from_xyz(40.0, 0.025, -40.0),
+        ))
+        .insert(Name::new("Nuclear Drop-Off"))
+        .insert(Volume(Aabb3d::new(Vec3::ZERO, Vec3::new(3.0, 2.0, 3.0))))
+        .insert(NuclearDropOff)
+        .insert(ContractId(132))
+        .insert(FakeLevelStuff);
+
     // supply contract loot cache 1
     commands
         .spawn((
@@ -961,30 +1432,16 @@ fn add_inventory_to_operators(
     }
 }
 
-fn add_squad_id_to_damage(
-    mut commands: Commands,
-    damage_origin_added: Query<(Entity, &DamageOrigin, Option<&SquadId>), Added<DamageOrigin>>,
-    squad_id_query: Query<(Entity, &SquadId)>,
-) {
-    for (added, damage_origin, squad_id) in damage_origin_added.iter() {
-        if squad_id.is_some() {
-            continue;
-        } else if let Ok((_, s)) = squad_id_query.get(damage_origin.0) {
-            commands.entity(added).insert(s.clone());
-        }
-    }
-}
-
-// TODO: how to connect DamageOrigin and ProjectileOrigin ???
+/// the hitbox cube inherits the damage origin chain of its projectile, see damage plugin
 fn add_cubes_to_projectiles(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    query: Query<(Entity, Option<&ProjectileOrigin>), Added<Projectile>>,
+    query: Query<Entity, Added<Projectile>>,
 ) {
     debug!("adding cubes to new projectiles");
-    for (added, origin) in query.iter() {
-        let entity = commands
+    for added in query.iter() {
+        commands
             .spawn((
                 Mesh3d(meshes.add(Cuboid::new(0.2, 0.2, 0.2))),
                 MeshMaterial3d(materials.add(StandardMaterial {
@@ -1004,13 +1461,7 @@ fn add_cubes_to_projectiles(
                 )),
                 Damage(10),
             ))
-            .set_parent(added)
-            .id();
-
-        // transfer if it is available
-        if let Some(o) = origin {
-            commands.entity(entity).insert(DamageOrigin(o.0));
-        }
+            .set_parent(added);
     }
 }
 
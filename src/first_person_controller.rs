@@ -3,11 +3,14 @@ use std::f32::consts::PI;
 use bevy::input::mouse::MouseButtonInput;
 use bevy::{app::Plugin, input::mouse::MouseMotion};
 
+use crate::armor::Armor;
 use crate::compass::Compass;
 use crate::coordinates::GridPosition;
+use crate::health::Health;
 use crate::heightmap::FlatEarth;
 use crate::projectile::SingleShot;
-use crate::raid::{RaidState, Volume};
+use crate::raid::{PlayerControlsEnabled, RaidState, Volume};
+use crate::vehicle::Driver;
 use crate::wallet::Wallet;
 use crate::AppState;
 use crate::AppState::Raid;
@@ -32,7 +35,12 @@ impl Plugin for FirstPersonControllerPlugin {
         app.add_systems(OnEnter(Raid), start_first_person_controller_system)
             .add_systems(
                 Update,
-                (update_camera_move, debug_im_crosshair).run_if(in_state(AppState::Raid)),
+                (debug_im_crosshair).run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(
+                Update,
+                (update_camera_move)
+                    .run_if(in_state(AppState::Raid).and(resource_exists::<PlayerControlsEnabled>)),
             )
             .add_systems(
                 Update,
@@ -41,7 +49,11 @@ impl Plugin for FirstPersonControllerPlugin {
                     update_camera_look_yaw,
                     update_camera_look_pitch,
                 )
-                    .run_if(in_state(AppState::Raid).and(in_state(RaidState::Raid))),
+                    .run_if(
+                        in_state(AppState::Raid)
+                            .and(in_state(RaidState::Raid))
+                            .and(resource_exists::<PlayerControlsEnabled>),
+                    ),
             )
             .add_systems(OnExit(AppState::Raid), bye_first_person_controller_system);
     }
@@ -113,6 +125,8 @@ pub fn start_first_person_controller_system(
             .into(),
         }))
         .insert(Wallet::default())
+        .insert(Health::default())
+        .insert(Armor::default())
         .add_child(camera)
         .add_child(capsule);
 }
@@ -121,7 +135,7 @@ pub fn start_first_person_controller_system(
 fn update_camera_move(
     time: Res<Time>,
     key_input: Res<ButtonInput<KeyCode>>,
-    mut query: Query<&mut Transform, (With<PlayerControlled>, With<Operator>)>,
+    mut query: Query<&mut Transform, (With<PlayerControlled>, With<Operator>, Without<Driver>)>,
 ) {
     debug!("updating {}", NAME);
     let dt = time.delta_secs();
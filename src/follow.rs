@@ -12,6 +12,9 @@ use bevy::prelude::*;
 
 // Constants
 const NAME: &str = "follow";
+/// followers further away than this many times their keep distance run to catch up
+const CATCH_UP_FACTOR: f32 = 4.0;
+const CATCH_UP_SPEED_FACTOR: f32 = 2.0;
 
 // Plugin
 pub struct FollowPlugin;
@@ -30,7 +33,18 @@ impl Plugin for FollowPlugin {
 // Components
 #[derive(Component, Reflect, InspectorOptions)]
 #[reflect(Component, InspectorOptions)]
-pub struct FollowTarget(Entity);
+pub struct FollowTarget(pub Entity);
+
+/// followers with a behaviour keep their distance and walk at a steady pace, the others creep
+/// towards their target
+#[derive(Component, Reflect, InspectorOptions)]
+#[reflect(Component, InspectorOptions)]
+pub struct FollowBehaviour {
+    /// meters per second
+    pub speed: f32,
+    /// the follower stops this close to the target
+    pub keep_distance: f32,
+}
 
 #[derive(Component, Reflect, InspectorOptions)]
 #[reflect(Component, InspectorOptions)]
@@ -58,12 +72,25 @@ fn start_follow_system(
 }
 
 fn update_follow_system(
-    mut followers: Query<(&FollowTarget, &GlobalTransform, &mut Transform)>,
+    time: Res<Time>,
+    mut followers: Query<(
+        &FollowTarget,
+        &GlobalTransform,
+        &mut Transform,
+        Option<&FollowBehaviour>,
+    )>,
     targets: Query<(Entity, &GlobalTransform), Without<FollowTarget>>,
 ) {
     debug!("updating {}", NAME);
     for mut follower in followers.iter_mut() {
-        if let Ok(target) = targets.get((follower.0).0) {
+        if let (Ok(target), Some(behaviour)) = (targets.get((follower.0).0), follower.3) {
+            let difference = (target.1.translation() - follower.1.translation()).with_y(0.0);
+            follower.2.translation += follow_step(difference, behaviour, time.delta_secs());
+            if difference.length() > f32::EPSILON {
+                let direction = difference.normalize();
+                follower.2.look_to(direction, Vec3::Y);
+            }
+        } else if let Ok(target) = targets.get((follower.0).0) {
             // factor for translation/rotation differrence
             let factor = 0.01;
             //let rot_factor = 0.1;
@@ -94,11 +121,41 @@ fn bye_follow_system(mut _commands: Commands) {
 
 // helper functions
 
+/// never closer than the keep distance, runs when far behind
+fn follow_step(difference: Vec3, behaviour: &FollowBehaviour, delta_secs: f32) -> Vec3 {
+    let distance = difference.length();
+    if distance <= behaviour.keep_distance {
+        return Vec3::ZERO;
+    }
+    let speed = if distance > behaviour.keep_distance * CATCH_UP_FACTOR {
+        behaviour.speed * CATCH_UP_SPEED_FACTOR
+    } else {
+        behaviour.speed
+    };
+    let step = (speed * delta_secs).min(distance - behaviour.keep_distance);
+    difference.normalize() * step
+}
+
 // tests
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
-    //use super::*;
+    use super::*;
+
+    #[test]
+    fn should_follow_at_steady_pace_and_keep_distance() {
+        // given
+        let behaviour = FollowBehaviour {
+            speed: 2.0,
+            keep_distance: 1.5,
+        };
+
+        // then
+        assert_eq!(Vec3::ZERO, follow_step(Vec3::X, &behaviour, 0.5));
+        assert_eq!(Vec3::X, follow_step(Vec3::X * 3.0, &behaviour, 0.5));
+        assert_eq!(Vec3::X * 0.5, follow_step(Vec3::X * 2.0, &behaviour, 0.5));
+        assert_eq!(Vec3::Z * 2.0, follow_step(Vec3::Z * 10.0, &behaviour, 0.5));
+    }
 
     /*
     #[test]
@@ -0,0 +1,323 @@
+use bevy::app::Plugin;
+
+use crate::damage::HealthDamageReceived;
+use crate::exfil::Operator;
+use crate::exfil_timers::GasSpreadingCountdown;
+use crate::health::Health;
+use crate::inventory::Inventory;
+use crate::loot::{Durability, Loot, LootType};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "gas";
+/// damage dealt to unprotected operators per gas tick
+const GAS_DAMAGE: i32 = 5;
+const GAS_TICK_SECS: f32 = 1.0;
+/// durability a gas mask loses per gas tick
+const GAS_MASK_DRAIN: u8 = 5;
+const GAS_WALL_HEIGHT: f32 = 30.0;
+
+// Plugin
+/// gas circle that starts closing with the gas spreading countdown
+pub struct GasPlugin;
+
+impl Plugin for GasPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GasCircleConfig>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    start_gas_circle.run_if(resource_added::<GasSpreadingCountdown>),
+                    update_gas_circle,
+                    update_gas_wall,
+                    gas_damage,
+                )
+                    .chain()
+                    .run_if(in_state(Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_gas);
+    }
+}
+
+// Components
+
+/// visual of the gas, a cylinder scaled to the current radius
+#[derive(Component)]
+struct GasWall;
+
+// Resources
+
+/// one shrinking step of the gas circle
+#[derive(Debug, Clone)]
+pub struct GasPhase {
+    pub target_radius: f32,
+    pub shrink_secs: f32,
+}
+
+/// configuration of the gas circle, insert before the raid to override. the default phases
+/// add up to the gas spreading countdown.
+#[derive(Resource, Debug, Clone)]
+pub struct GasCircleConfig {
+    pub center: Vec3,
+    pub start_radius: f32,
+    pub phases: Vec<GasPhase>,
+}
+
+impl Default for GasCircleConfig {
+    fn default() -> Self {
+        GasCircleConfig {
+            center: Vec3::ZERO,
+            start_radius: 60.0,
+            phases: vec![
+                GasPhase {
+                    target_radius: 40.0,
+                    shrink_secs: 10.0,
+                },
+                GasPhase {
+                    target_radius: 20.0,
+                    shrink_secs: 10.0,
+                },
+                GasPhase {
+                    target_radius: 0.0,
+                    shrink_secs: 10.0,
+                },
+            ],
+        }
+    }
+}
+
+/// the active gas circle, everything outside of the radius is gas
+#[derive(Resource, Debug)]
+pub struct GasCircle {
+    pub center: Vec3,
+    pub radius: f32,
+    phases: Vec<GasPhase>,
+    current_phase: usize,
+    phase_start_radius: f32,
+    phase_timer: Timer,
+    tick_timer: Timer,
+}
+
+impl GasCircle {
+    fn new(config: &GasCircleConfig) -> Self {
+        GasCircle {
+            center: config.center,
+            radius: config.start_radius,
+            phases: config.phases.clone(),
+            current_phase: 0,
+            phase_start_radius: config.start_radius,
+            phase_timer: phase_timer(config.phases.first()),
+            tick_timer: Timer::from_seconds(GAS_TICK_SECS, TimerMode::Repeating),
+        }
+    }
+
+    /// horizontal check, height does not matter for the gas
+    pub fn contains(&self, position: Vec3) -> bool {
+        self.distance_to_edge(position) > 0.0
+    }
+
+    /// positive inside the gas, negative inside the safe zone
+    pub fn distance_to_edge(&self, position: Vec3) -> f32 {
+        position.xz().distance(self.center.xz()) - self.radius
+    }
+}
+
+// Events
+
+// Systems
+fn start_gas_circle(mut commands: Commands, config: Res<GasCircleConfig>) {
+    debug!("starting {}", NAME);
+    commands.insert_resource(GasCircle::new(&config));
+}
+
+fn update_gas_circle(time: Res<Time>, gas: Option<ResMut<GasCircle>>) {
+    debug!("updating {}", NAME);
+    let Some(mut gas) = gas else {
+        return;
+    };
+    let Some(phase) = gas.phases.get(gas.current_phase).cloned() else {
+        return;
+    };
+    gas.phase_timer.tick(time.delta());
+    gas.radius = shrunk_radius(
+        gas.phase_start_radius,
+        phase.target_radius,
+        gas.phase_timer.fraction(),
+    );
+    if gas.phase_timer.finished() {
+        gas.current_phase += 1;
+        gas.phase_start_radius = phase.target_radius;
+        gas.phase_timer = phase_timer(gas.phases.get(gas.current_phase));
+        debug!("gas phase {} reached", gas.current_phase);
+    }
+}
+
+fn update_gas_wall(
+    mut commands: Commands,
+    gas: Option<Res<GasCircle>>,
+    mut wall: Query<&mut Transform, With<GasWall>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(gas) = gas else {
+        return;
+    };
+    let scale = Vec3::new(gas.radius, 1.0, gas.radius);
+    if let Ok(mut transform) = wall.get_single_mut() {
+        transform.scale = scale;
+    } else {
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Cylinder::new(1.0, GAS_WALL_HEIGHT))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::srgba(0.6, 0.3, 0.0, 0.25),
+                    alpha_mode: AlphaMode::Blend,
+                    cull_mode: None,
+                    ..default()
+                })),
+                Transform::from_translation(gas.center).with_scale(scale),
+            ))
+            .insert(Name::new("Gas Wall"))
+            .insert(GasWall);
+    }
+}
+
+/// periodic damage for operators within the gas. a gas mask in the backpack takes the hit
+/// instead, as long as it has durability left.
+#[allow(clippy::type_complexity)]
+fn gas_damage(
+    time: Res<Time>,
+    gas: Option<ResMut<GasCircle>>,
+    wall: Query<Entity, With<GasWall>>,
+    operators: Query<(Entity, &GlobalTransform), (With<Operator>, With<Health>)>,
+    inventories: Query<&Parent, With<Inventory>>,
+    mut gas_masks: Query<(&Parent, &LootType, &mut Durability), With<Loot>>,
+    mut health_damage: EventWriter<HealthDamageReceived>,
+) {
+    let Some(mut gas) = gas else {
+        return;
+    };
+    if !gas.tick_timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    // the wall is what shows up as the dealer of gas damage, e.g. in the kill feed
+    let dealer = wall.get_single().ok();
+    for (operator, transform) in operators.iter() {
+        if !gas.contains(transform.translation()) {
+            continue;
+        }
+        let gas_mask = gas_masks
+            .iter_mut()
+            .filter(|(_, loot_type, durability)| {
+                **loot_type == LootType::CircleDefense && durability.current > 0
+            })
+            .find(|(backpack, _, _)| {
+                inventories
+                    .get(backpack.get())
+                    .is_ok_and(|owner| owner.get() == operator)
+            });
+        if let Some((_, _, mut durability)) = gas_mask {
+            durability.current = durability.current.saturating_sub(GAS_MASK_DRAIN);
+            debug!("gas mask of {} drained to {}", operator, durability.current);
+        } else {
+            debug!("{} takes gas damage", operator);
+            health_damage.send(HealthDamageReceived {
+                entity: operator,
+                damage: GAS_DAMAGE,
+                dealer,
+                weapon: None,
+            });
+        }
+    }
+}
+
+fn bye_gas(mut commands: Commands, wall: Query<Entity, With<GasWall>>) {
+    debug!("stopping {}", NAME);
+    commands.remove_resource::<GasCircle>();
+    for entity in wall.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// helper functions
+
+fn phase_timer(phase: Option<&GasPhase>) -> Timer {
+    Timer::from_seconds(phase.map_or(0.0, |p| p.shrink_secs), TimerMode::Once)
+}
+
+fn shrunk_radius(from: f32, to: f32, fraction: f32) -> f32 {
+    from + (to - from) * fraction.clamp(0.0, 1.0)
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn should_shrink_radius() {
+        assert_eq!(60.0, shrunk_radius(60.0, 40.0, 0.0));
+        assert_eq!(50.0, shrunk_radius(60.0, 40.0, 0.5));
+        assert_eq!(40.0, shrunk_radius(60.0, 40.0, 1.0));
+    }
+
+    #[test]
+    fn should_only_contain_positions_outside_safe_zone() {
+        // given
+        let gas = GasCircle::new(&GasCircleConfig::default());
+
+        // then
+        assert!(!gas.contains(Vec3::new(10.0, 100.0, 10.0)));
+        assert!(gas.contains(Vec3::new(60.0, 0.0, 10.0)));
+    }
+
+    #[test]
+    fn should_drain_gas_mask_instead_of_health() {
+        // given
+        let mut app = App::new();
+        app.add_event::<HealthDamageReceived>();
+        app.init_resource::<Time>();
+        app.insert_resource(GasCircle::new(&GasCircleConfig::default()));
+        app.add_systems(Update, gas_damage);
+        let in_gas = GlobalTransform::from_xyz(100.0, 0.0, 0.0);
+        let masked = app.world_mut().spawn((Operator, Health(100), in_gas)).id();
+        let unmasked = app.world_mut().spawn((Operator, Health(100), in_gas)).id();
+        let wall = app.world_mut().spawn(GasWall).id();
+        let backpack = app.world_mut().spawn(Inventory).set_parent(masked).id();
+        let gas_mask = app
+            .world_mut()
+            .spawn((Loot, LootType::CircleDefense, Durability::default()))
+            .set_parent(backpack)
+            .id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(GAS_TICK_SECS));
+        app.update();
+
+        // then
+        assert_eq!(
+            100 - GAS_MASK_DRAIN,
+            app.world().get::<Durability>(gas_mask).unwrap().current
+        );
+        let health_damage_events = app.world().resource::<Events<HealthDamageReceived>>();
+        let mut health_damage_reader = health_damage_events.get_cursor();
+        let received: Vec<&HealthDamageReceived> =
+            health_damage_reader.read(health_damage_events).collect();
+        assert_eq!(
+            vec![&HealthDamageReceived {
+                entity: unmasked,
+                damage: GAS_DAMAGE,
+                dealer: Some(wall),
+                weapon: None,
+            }],
+            received
+        );
+    }
+}
@@ -67,6 +67,7 @@ fn damage_received_listener(
                     dying.send(EntityDie {
                         dying: entity,
                         killer: event.dealer,
+                        weapon: event.weapon,
                     });
                 }
             }
@@ -107,6 +108,7 @@ mod tests {
                 entity,
                 damage: 10,
                 dealer: Option::None,
+                weapon: Option::None,
             });
 
         // Run systems
@@ -0,0 +1,424 @@
+use bevy::app::Plugin;
+use bevy::utils::{HashMap, HashSet};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::contracts::{
+    contract_squad, ended_contracts, ContractAccepted, ContractCancelled, ContractCompleted,
+    ContractFailed, ContractGeneralArea, ContractId, ContractState, ContractStateMachine,
+    ContractType, Contracts, FailContract,
+};
+use crate::death::EntityDied;
+use crate::exfil::{ExfilExitedAO, Operator};
+use crate::squad::{SquadId, Squads};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "hunt squad";
+/// seconds between two reveals of the target squad
+const REVEAL_SECS: f32 = 30.0;
+const START_RADIUS: f32 = 60.0;
+const MIN_RADIUS: f32 = 10.0;
+/// the area shrinks by this factor with every reveal
+const SHRINK_FACTOR: f32 = 0.75;
+
+// Plugin
+/// contract to eliminate every operator of another squad before it exfils
+pub struct HuntSquadPlugin;
+
+impl Plugin for HuntSquadPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_hunt_squad)
+            .add_systems(
+                Update,
+                (
+                    pick_target_squad,
+                    reveal_target_squad,
+                    target_operator_died,
+                    target_operator_exfilled,
+                    hunt_ended,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_hunt_squad);
+    }
+}
+
+// Components
+
+// Resources
+
+#[derive(Debug)]
+struct Hunt {
+    hunter: SquadId,
+    target: SquadId,
+    /// target operators still in the raid
+    remaining: HashSet<Entity>,
+    hunter_kills: u32,
+    reveal: Timer,
+    radius: f32,
+    area: Option<Entity>,
+}
+
+/// running hunts by contract
+#[derive(Resource, Default)]
+struct Hunts {
+    map: HashMap<ContractId, Hunt>,
+}
+
+// Events
+
+// Systems
+fn start_hunt_squad(mut commands: Commands) {
+    debug!("starting {}", NAME);
+    commands.init_resource::<Hunts>();
+}
+
+/// any other squad with operators in the raid can be the target
+fn pick_target_squad(
+    mut accepted: EventReader<ContractAccepted>,
+    contracts: Res<Contracts>,
+    squads: Res<Squads>,
+    hunts: Option<ResMut<Hunts>>,
+    operators: Query<(Entity, &SquadId), With<Operator>>,
+    mut fail: EventWriter<FailContract>,
+) {
+    let Some(mut hunts) = hunts else {
+        return;
+    };
+    for event in accepted.read() {
+        let is_hunt_contract = contracts
+            .map
+            .get(&event.contract_id)
+            .is_some_and(|contract| contract.contract_type == ContractType::HuntSquad);
+        if !is_hunt_contract {
+            continue;
+        }
+        let Some(hunter) = contract_squad(&squads, event.contract_id) else {
+            continue;
+        };
+        let mut candidates: Vec<&SquadId> = operators
+            .iter()
+            .map(|(_, squad_id)| squad_id)
+            .filter(|squad_id| *squad_id != hunter)
+            .collect();
+        candidates.sort_by_key(|squad_id| squad_id.0);
+        candidates.dedup();
+        let Some(target) = candidates.choose(&mut rand::thread_rng()) else {
+            warn!("no squad to hunt for contract {:?}", event.contract_id);
+            fail.send(FailContract {
+                contract_id: event.contract_id,
+            });
+            continue;
+        };
+        debug!("squad {:?} hunts squad {:?}", hunter, target);
+        let remaining = operators
+            .iter()
+            .filter(|(_, squad_id)| squad_id == target)
+            .map(|(operator, _)| operator)
+            .collect();
+        let mut reveal = Timer::from_seconds(REVEAL_SECS, TimerMode::Repeating);
+        // the first area is revealed right away
+        reveal.tick(reveal.duration());
+        hunts.map.insert(
+            event.contract_id,
+            Hunt {
+                hunter: hunter.clone(),
+                target: (*target).clone(),
+                remaining,
+                hunter_kills: 0,
+                reveal,
+                radius: START_RADIUS,
+                area: None,
+            },
+        );
+    }
+}
+
+/// every reveal is closer to the target squad, but never its exact position
+fn reveal_target_squad(
+    mut commands: Commands,
+    time: Res<Time>,
+    hunts: Option<ResMut<Hunts>>,
+    operators: Query<&GlobalTransform, With<Operator>>,
+) {
+    let Some(mut hunts) = hunts else {
+        return;
+    };
+    let mut rng = rand::thread_rng();
+    for (contract_id, hunt) in hunts.map.iter_mut() {
+        if !hunt.reveal.finished() {
+            hunt.reveal.tick(time.delta());
+        }
+        if !hunt.reveal.finished() {
+            continue;
+        }
+        hunt.reveal.reset();
+        let positions: Vec<Vec3> = hunt
+            .remaining
+            .iter()
+            .filter_map(|operator| operators.get(*operator).ok())
+            .map(|transform| transform.translation())
+            .collect();
+        if positions.is_empty() {
+            continue;
+        }
+        let center = positions.iter().sum::<Vec3>() / positions.len() as f32;
+        let offset =
+            Vec3::new(rng.gen_range(-1.0..1.0), 0.0, rng.gen_range(-1.0..1.0)) * hunt.radius * 0.5;
+        if let Some(area) = hunt.area.take() {
+            commands.entity(area).despawn_recursive();
+        }
+        debug!("squad {:?} revealed within {}m", hunt.target, hunt.radius);
+        hunt.area = Some(
+            commands
+                .spawn(Transform::from_translation(center + offset))
+                .insert(Name::new("Hunted Squad General Area"))
+                .insert(ContractGeneralArea::new(hunt.radius))
+                .insert(*contract_id)
+                .id(),
+        );
+        hunt.radius = shrink_radius(hunt.radius);
+    }
+}
+
+/// the hunt is over once no target operator is left, it only counts if the hunters got kills
+fn target_operator_died(
+    mut commands: Commands,
+    mut died: EventReader<EntityDied>,
+    hunts: Option<ResMut<Hunts>>,
+    mut contracts: ResMut<Contracts>,
+    mut completed: EventWriter<ContractCompleted>,
+    mut fail: EventWriter<FailContract>,
+) {
+    let Some(mut hunts) = hunts else {
+        return;
+    };
+    for event in died.read() {
+        let Some((&contract_id, hunt)) = hunts
+            .map
+            .iter_mut()
+            .find(|(_, hunt)| hunt.remaining.contains(&event.death))
+        else {
+            continue;
+        };
+        hunt.remaining.remove(&event.death);
+        if event.killer_squad.as_ref() == Some(&hunt.hunter) {
+            hunt.hunter_kills += 1;
+        }
+        if !hunt.remaining.is_empty() {
+            continue;
+        }
+        let hunter_kills = hunt.hunter_kills;
+        if let Some(hunt) = hunts.map.remove(&contract_id) {
+            if let Some(area) = hunt.area {
+                commands.entity(area).despawn_recursive();
+            }
+        }
+        if hunter_kills == 0 {
+            debug!(
+                "squad of contract {:?} died without the hunters",
+                contract_id
+            );
+            fail.send(FailContract { contract_id });
+            continue;
+        }
+        let Some(contract) = contracts.map.get_mut(&contract_id) else {
+            continue;
+        };
+        if let ContractState::HuntSquad(mut state) = contract.contract_state {
+            debug!("hunted squad of contract {:?} eliminated", contract_id);
+            contract.contract_state = state.next();
+            completed.send(ContractCompleted { contract_id });
+        }
+    }
+}
+
+/// a target operator that made it out ends the hunt
+fn target_operator_exfilled(
+    mut commands: Commands,
+    mut exfilled: EventReader<ExfilExitedAO>,
+    hunts: Option<ResMut<Hunts>>,
+    mut fail: EventWriter<FailContract>,
+) {
+    let Some(mut hunts) = hunts else {
+        return;
+    };
+    for event in exfilled.read() {
+        let Some(contract_id) = hunts
+            .map
+            .iter()
+            .find(|(_, hunt)| hunt.remaining.contains(&event.operator_entity))
+            .map(|(contract_id, _)| *contract_id)
+        else {
+            continue;
+        };
+        debug!("hunted squad of contract {:?} exfilled", contract_id);
+        if let Some(hunt) = hunts.map.remove(&contract_id) {
+            if let Some(area) = hunt.area {
+                commands.entity(area).despawn_recursive();
+            }
+        }
+        fail.send(FailContract { contract_id });
+    }
+}
+
+/// a failed or cancelled hunt stops revealing the target squad, its area is removed with the
+/// contract
+fn hunt_ended(
+    mut failed: EventReader<ContractFailed>,
+    mut cancelled: EventReader<ContractCancelled>,
+    hunts: Option<ResMut<Hunts>>,
+) {
+    let Some(mut hunts) = hunts else {
+        return;
+    };
+    for contract_id in ended_contracts(&mut failed, &mut cancelled) {
+        if hunts.map.remove(&contract_id).is_some() {
+            debug!("hunt of contract {:?} is over", contract_id);
+        }
+    }
+}
+
+fn bye_hunt_squad(mut commands: Commands) {
+    debug!("stopping {}", NAME);
+    commands.remove_resource::<Hunts>();
+}
+
+// helper functions
+
+fn shrink_radius(radius: f32) -> f32 {
+    (radius * SHRINK_FACTOR).max(MIN_RADIUS)
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::contracts::{insert_held_contract, HuntSquadState};
+    use crate::death::DeathKind;
+
+    fn hunt_app(contract_id: ContractId, remaining: &[Entity]) -> App {
+        let mut app = App::new();
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::HuntSquad,
+            ContractState::HuntSquad(HuntSquadState::Started),
+        );
+        app.insert_resource(Hunts {
+            map: [(
+                contract_id,
+                Hunt {
+                    hunter: SquadId(1),
+                    target: SquadId(2),
+                    remaining: remaining.iter().copied().collect(),
+                    hunter_kills: 0,
+                    reveal: Timer::from_seconds(REVEAL_SECS, TimerMode::Repeating),
+                    radius: START_RADIUS,
+                    area: None,
+                },
+            )]
+            .into_iter()
+            .collect(),
+        });
+        app
+    }
+
+    fn killed_by_hunter(death: Entity) -> EntityDied {
+        EntityDied {
+            death,
+            killer: None,
+            killer_squad: Some(SquadId(1)),
+            death_squad: Some(SquadId(2)),
+            death_kind: DeathKind::Operator,
+            death_name: None,
+            weapon: None,
+            assists: vec![],
+        }
+    }
+
+    #[test]
+    fn should_shrink_radius_down_to_minimum() {
+        // then
+        assert_eq!(45.0, shrink_radius(START_RADIUS));
+        assert_eq!(MIN_RADIUS, shrink_radius(MIN_RADIUS + 1.0));
+    }
+
+    #[test]
+    fn should_complete_hunt_when_last_target_operator_died() {
+        // given
+        let mut world = World::new();
+        let first = world.spawn_empty().id();
+        let second = world.spawn_empty().id();
+        let contract_id = ContractId(41);
+        let mut app = hunt_app(contract_id, &[first, second]);
+        app.add_event::<EntityDied>();
+        app.add_event::<ContractCompleted>();
+        app.add_event::<FailContract>();
+        app.add_systems(Update, target_operator_died);
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<EntityDied>>()
+            .send(killed_by_hunter(first));
+        app.update();
+
+        // then
+        assert!(app
+            .world()
+            .resource::<Events<ContractCompleted>>()
+            .is_empty());
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<EntityDied>>()
+            .send(killed_by_hunter(second));
+        app.update();
+
+        // then
+        assert_eq!(
+            ContractState::HuntSquad(HuntSquadState::SquadEliminated),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        let completed_events = app.world().resource::<Events<ContractCompleted>>();
+        let mut completed_reader = completed_events.get_cursor();
+        assert_eq!(
+            vec![&ContractCompleted { contract_id }],
+            completed_reader.read(completed_events).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_fail_hunt_when_target_operator_exfilled() {
+        // given
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        let contract_id = ContractId(42);
+        let mut app = hunt_app(contract_id, &[target]);
+        app.add_event::<ExfilExitedAO>();
+        app.add_event::<FailContract>();
+        app.add_systems(Update, target_operator_exfilled);
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<ExfilExitedAO>>()
+            .send(ExfilExitedAO {
+                operator_entity: target,
+            });
+        app.update();
+
+        // then
+        assert!(app.world().resource::<Hunts>().map.is_empty());
+        let fail_events = app.world().resource::<Events<FailContract>>();
+        let mut fail_reader = fail_events.get_cursor();
+        assert_eq!(
+            vec![&FailContract { contract_id }],
+            fail_reader.read(fail_events).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use bevy::app::Plugin;
+
+use crate::exfil::Operator;
+use crate::raid::{InfilComplete, InfilCountdown};
+use crate::spawn::Spawn;
+use crate::squad::SquadId;
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "infil";
+/// where the helicopter starts, relative to its landing point
+const INFIL_APPROACH_OFFSET: Vec3 = Vec3::new(-120.0, 60.0, -120.0);
+/// the helicopter comes in above the landing point and descends from here
+const INFIL_HOVER_HEIGHT: f32 = 20.0;
+/// the body of the helicopter rests this far above the spawn
+const HELICOPTER_LANDING_HEIGHT: f32 = 1.0;
+const HELICOPTER_DEPART_SECS: f32 = 10.0;
+const HELICOPTER_DEPART_SPEED: f32 = 15.0;
+
+// Plugin
+/// squads are flown in by helicopter and dropped off at their spawn when the infil is complete
+pub struct InfilPlugin;
+
+impl Plugin for InfilPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_infil)
+            .add_systems(
+                Update,
+                (
+                    spawn_infil_helicopters,
+                    board_infil_helicopters,
+                    fly_infil_helicopters,
+                    update_seated_operators,
+                    touch_down,
+                    depart_infil_helicopters,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_infil);
+    }
+}
+
+// Components
+
+/// helicopter flying a squad to its spawn
+#[derive(Component, Debug)]
+pub struct InfilHelicopter {
+    pub spawn: Entity,
+    pub squad: SquadId,
+    /// control points of the cubic bezier from the approach to the landing point
+    path: [Vec3; 4],
+}
+
+/// operator sitting in an infil helicopter, moves along with it
+#[derive(Component, Debug)]
+pub struct Seated {
+    pub helicopter: Entity,
+    pub seat: usize,
+}
+
+/// operator was dropped off and is not picked up by an infil helicopter again
+#[derive(Component, Debug)]
+pub struct Infilled;
+
+/// helicopter after the drop off, flies away and is gone when the timer is done
+#[derive(Component)]
+struct Departing(Timer);
+
+// Resources
+
+// Events
+
+// Systems
+fn start_infil(mut _commands: Commands) {
+    debug!("starting {}", NAME);
+}
+
+/// one helicopter per squad spawn
+fn spawn_infil_helicopters(
+    mut commands: Commands,
+    spawns: Query<(Entity, &Transform, &SquadId), Added<Spawn>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (spawn, transform, squad_id) in spawns.iter() {
+        debug!("infil helicopter for squad {:?}", squad_id);
+        let path = flight_path(transform.translation + Vec3::Y * HELICOPTER_LANDING_HEIGHT);
+        let heading = Vec3::new(path[3].x, path[0].y, path[3].z);
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Cuboid::new(2.5, 2.0, 6.0))),
+                MeshMaterial3d(materials.add(Color::srgb(0.25, 0.3, 0.2))),
+                Transform::from_translation(path[0]).looking_at(heading, Vec3::Y),
+            ))
+            .insert(Name::new("Infil Helicopter"))
+            .insert(InfilHelicopter {
+                spawn,
+                squad: squad_id.clone(),
+                path,
+            });
+    }
+}
+
+/// operators take the next free seat in the helicopter of their squad
+#[allow(clippy::type_complexity)]
+fn board_infil_helicopters(
+    mut commands: Commands,
+    helicopters: Query<(Entity, &InfilHelicopter), Without<Departing>>,
+    seated: Query<&Seated>,
+    operators: Query<(Entity, &SquadId), (With<Operator>, Without<Seated>, Without<Infilled>)>,
+) {
+    for (helicopter, infil) in helicopters.iter() {
+        let taken = seated.iter().filter(|s| s.helicopter == helicopter).count();
+        for (seat, (operator, _)) in operators
+            .iter()
+            .filter(|(_, squad_id)| **squad_id == infil.squad)
+            .enumerate()
+            .map(|(index, boarding)| (taken + index, boarding))
+        {
+            debug!("operator {} boards seat {}", operator, seat);
+            commands
+                .entity(operator)
+                .insert(Seated { helicopter, seat });
+        }
+    }
+}
+
+/// the flight follows the infil countdown, touch down is when the countdown is done
+fn fly_infil_helicopters(
+    infil_countdown: Option<Res<InfilCountdown>>,
+    mut helicopters: Query<(&InfilHelicopter, &mut Transform), Without<Departing>>,
+) {
+    let Some(infil_countdown) = infil_countdown else {
+        return;
+    };
+    for (infil, mut transform) in helicopters.iter_mut() {
+        transform.translation = flight_position(&infil.path, infil_countdown.fraction());
+    }
+}
+
+fn update_seated_operators(
+    helicopters: Query<&Transform, With<InfilHelicopter>>,
+    mut operators: Query<(&Seated, &mut Transform), Without<InfilHelicopter>>,
+) {
+    for (seated, mut transform) in operators.iter_mut() {
+        if let Ok(helicopter) = helicopters.get(seated.helicopter) {
+            *transform =
+                helicopter.mul_transform(Transform::from_translation(seat_offset(seated.seat)));
+        }
+    }
+}
+
+/// releases all seated operators into the formation of their spawn
+fn touch_down(
+    mut commands: Commands,
+    mut complete: EventReader<InfilComplete>,
+    helicopters: Query<(Entity, &InfilHelicopter), Without<Departing>>,
+    spawns: Query<(&Spawn, &Transform)>,
+    mut operators: Query<(Entity, &Seated, &mut Transform), Without<Spawn>>,
+) {
+    if complete.read().count() == 0 {
+        return;
+    }
+    for (helicopter, infil) in helicopters.iter() {
+        debug!("infil helicopter {} touched down", helicopter);
+        let Ok((spawn, spawn_transform)) = spawns.get(infil.spawn) else {
+            continue;
+        };
+        let mut passengers: Vec<_> = operators
+            .iter_mut()
+            .filter(|(_, seated, _)| seated.helicopter == helicopter)
+            .collect();
+        passengers.sort_by_key(|(_, seated, _)| seated.seat);
+        for (slot, (operator, _, mut transform)) in passengers.into_iter().enumerate() {
+            *transform = Transform::from_translation(
+                spawn_transform.transform_point(spawn.formation.slot(slot)),
+            )
+            .with_rotation(spawn_transform.rotation);
+            commands
+                .entity(operator)
+                .remove::<Seated>()
+                .insert(Infilled);
+        }
+        commands
+            .entity(helicopter)
+            .insert(Departing(Timer::from_seconds(
+                HELICOPTER_DEPART_SECS,
+                TimerMode::Once,
+            )));
+    }
+}
+
+fn depart_infil_helicopters(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut helicopters: Query<(Entity, &mut Transform, &mut Departing)>,
+) {
+    for (helicopter, mut transform, mut departing) in helicopters.iter_mut() {
+        let direction = (*transform.forward() + Vec3::Y).normalize();
+        transform.translation += direction * HELICOPTER_DEPART_SPEED * time.delta_secs();
+        if departing.0.tick(time.delta()).finished() {
+            debug!("infil helicopter {} is gone", helicopter);
+            commands.entity(helicopter).despawn_recursive();
+        }
+    }
+}
+
+fn bye_infil(mut commands: Commands, helicopters: Query<Entity, With<InfilHelicopter>>) {
+    debug!("stopping {}", NAME);
+    for helicopter in helicopters.iter() {
+        commands.entity(helicopter).despawn_recursive();
+    }
+}
+
+// helper functions
+
+/// approach from far away, come in above the landing point and descend
+fn flight_path(landing: Vec3) -> [Vec3; 4] {
+    let start = landing + INFIL_APPROACH_OFFSET;
+    let hover = landing + Vec3::Y * INFIL_HOVER_HEIGHT;
+    [start, start.lerp(hover, 0.5), hover, landing]
+}
+
+/// point on the cubic bezier of the flight path
+fn flight_position(path: &[Vec3; 4], fraction: f32) -> Vec3 {
+    let t = fraction.clamp(0.0, 1.0);
+    let u = 1.0 - t;
+    path[0] * u * u * u
+        + path[1] * 3.0 * u * u * t
+        + path[2] * 3.0 * u * t * t
+        + path[3] * t * t * t
+}
+
+/// two seats per row, left and right
+fn seat_offset(seat: usize) -> Vec3 {
+    let side = if seat.is_multiple_of(2) { -0.7 } else { 0.7 };
+    Vec3::new(side, 0.2, (seat / 2) as f32 * 1.2 - 1.2)
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::spawn::Formation;
+
+    #[test]
+    fn should_fly_from_approach_to_landing() {
+        // given
+        let landing = Vec3::new(-4.0, 1.0, 5.0);
+        let path = flight_path(landing);
+
+        // then
+        assert_eq!(landing + INFIL_APPROACH_OFFSET, flight_position(&path, 0.0));
+        assert_eq!(landing, flight_position(&path, 1.0));
+        assert_eq!(landing, flight_position(&path, 2.0));
+    }
+
+    #[test]
+    fn should_drop_off_seated_operators_in_formation() {
+        // given
+        let mut app = App::new();
+        app.add_event::<InfilComplete>();
+        app.add_systems(Update, touch_down);
+        let spawn = app
+            .world_mut()
+            .spawn(Spawn {
+                formation: Formation::Line,
+            })
+            .insert(Transform::from_xyz(-4.0, 0.0, 5.0))
+            .id();
+        let helicopter = app
+            .world_mut()
+            .spawn(InfilHelicopter {
+                spawn,
+                squad: SquadId(111),
+                path: flight_path(Vec3::ZERO),
+            })
+            .id();
+        let second = app
+            .world_mut()
+            .spawn((
+                Operator,
+                Seated {
+                    helicopter,
+                    seat: 1,
+                },
+                Transform::default(),
+            ))
+            .id();
+        let first = app
+            .world_mut()
+            .spawn((
+                Operator,
+                Seated {
+                    helicopter,
+                    seat: 0,
+                },
+                Transform::default(),
+            ))
+            .id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<InfilComplete>>()
+            .send(InfilComplete);
+        app.update();
+
+        // then
+        assert_eq!(
+            Vec3::new(-4.0, 0.0, 5.0),
+            app.world().get::<Transform>(first).unwrap().translation
+        );
+        assert_eq!(
+            Vec3::new(-2.0, 0.0, 5.0),
+            app.world().get::<Transform>(second).unwrap().translation
+        );
+        assert!(app.world().get::<Seated>(first).is_none());
+        assert!(app.world().get::<Infilled>(second).is_some());
+        assert!(app.world().get::<Departing>(helicopter).is_some());
+    }
+}
@@ -3,12 +3,16 @@ use bevy::math::bounding::{Aabb3d, RayCast3d};
 use bevy::render::primitives::{Aabb, Frustum};
 
 use crate::first_person_controller::FirstPersonCamera;
-use crate::raid::RaidState;
+use crate::raid::{PlayerControlsEnabled, RaidState};
 use crate::AppState;
 use bevy::prelude::*;
 
 // Constants
 const NAME: &str = "interaction";
+const PROGRESS_BAR_WIDTH: f32 = 200.0;
+const PROGRESS_BAR_HEIGHT: f32 = 8.0;
+const PROGRESS_BAR_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const PROGRESS_BAR_BACKGROUND_COLOR: Color = Color::srgba(0.1, 0.1, 0.1, 0.6);
 
 // Plugin
 pub struct InteractionPlugin;
@@ -19,8 +23,14 @@ impl Plugin for InteractionPlugin {
             .add_event::<Interact>()
             .add_systems(
                 Update,
-                (interaction).run_if(in_state(AppState::Raid).and(in_state(RaidState::Raid))),
-            );
+                (interaction).run_if(
+                    in_state(AppState::Raid)
+                        .and(in_state(RaidState::Raid))
+                        .and(resource_exists::<PlayerControlsEnabled>),
+                ),
+            )
+            .add_systems(Update, (hold_progress_bar).run_if(in_state(AppState::Raid)))
+            .add_systems(OnExit(AppState::Raid), bye_interaction);
     }
 }
 
@@ -29,8 +39,40 @@ impl Plugin for InteractionPlugin {
 #[derive(Component)]
 pub struct Interactable;
 
+/// interactables that need the interaction key held down for the given seconds
+#[derive(Component, Debug)]
+pub struct HoldInteractable(pub f32);
+
+/// progress bar of the running hold interaction
+#[derive(Component)]
+struct HoldProgressBar;
+
+#[derive(Component)]
+struct HoldProgressFill;
+
 // Resources
 
+/// the hold interaction in progress, gone as soon as the key is released or the target changes
+#[derive(Resource, Debug, PartialEq)]
+pub struct HoldInteraction {
+    pub interaction_entity: Entity,
+    pub operator_entity: Entity,
+    /// seconds held so far
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+impl HoldInteraction {
+    /// between 0 and 1
+    pub fn progress(&self) -> f32 {
+        (self.elapsed / self.duration).clamp(0.0, 1.0)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
 // Events
 #[derive(Event, Debug, PartialEq)]
 pub struct InventoryInteracted {
@@ -50,9 +92,15 @@ pub struct Interact {
 
 /// system that checks for entities to interact with, render gizmo and sending out a generic command message that can be used to further process the interaction without having to do all the raycasting and stuff again.
 /// emits a ```Interact``` command/event that can be used by other listeners to act on.
+/// for a ```HoldInteractable``` the command is only sent once the key was held down long enough.
+#[allow(clippy::too_many_arguments)]
 fn interaction(
+    mut commands: Commands,
+    time: Res<Time>,
     interact_probe: Query<(&Frustum, &GlobalTransform, Entity, &Parent), With<FirstPersonCamera>>,
     interactable_query: Query<(Entity, &Aabb, &GlobalTransform, &Name), With<Interactable>>,
+    hold_interactables: Query<&HoldInteractable>,
+    hold_interaction: Option<Res<HoldInteraction>>,
     mut gizmos: Gizmos,
     key_input: Res<ButtonInput<KeyCode>>,
     mut interact_command: EventWriter<Interact>,
@@ -98,23 +146,182 @@ fn interaction(
     let first = closest.first();
     debug!("the closest one is: {:?}", first);
     if let Some((_, entity, name)) = first {
-        if key_input.just_released(KeyCode::KeyF) {
-            debug!("interacting with entity {:?}", name);
-            interact_command.send(Interact {
-                interaction_entity: *entity,
-                operator_entity: probe.3.get(),
-            });
+        if let Ok(hold) = hold_interactables.get(*entity) {
+            if !key_input.pressed(KeyCode::KeyF) {
+                commands.remove_resource::<HoldInteraction>();
+                return;
+            }
+            let held = hold_step(
+                hold_interaction.as_deref(),
+                *entity,
+                probe.3.get(),
+                hold.0,
+                time.delta_secs(),
+            );
+            if held.is_complete() {
+                debug!("interacting with entity {:?} after holding", name);
+                interact_command.send(Interact {
+                    interaction_entity: *entity,
+                    operator_entity: probe.3.get(),
+                });
+                commands.remove_resource::<HoldInteraction>();
+            } else {
+                commands.insert_resource(held);
+            }
+        } else {
+            commands.remove_resource::<HoldInteraction>();
+            if key_input.just_released(KeyCode::KeyF) {
+                debug!("interacting with entity {:?}", name);
+                interact_command.send(Interact {
+                    interaction_entity: *entity,
+                    operator_entity: probe.3.get(),
+                });
+            }
+        }
+    } else {
+        commands.remove_resource::<HoldInteraction>();
+    }
+}
+
+/// shows the progress bar below the crosshair while a hold interaction is running
+fn hold_progress_bar(
+    mut commands: Commands,
+    hold_interaction: Option<Res<HoldInteraction>>,
+    bars: Query<Entity, With<HoldProgressBar>>,
+    mut fills: Query<&mut Node, With<HoldProgressFill>>,
+) {
+    let Some(hold_interaction) = hold_interaction else {
+        for bar in bars.iter() {
+            commands.entity(bar).despawn_recursive();
         }
+        return;
+    };
+    let width = Val::Percent(hold_interaction.progress() * 100.0);
+    if bars.is_empty() {
+        spawn_progress_bar(&mut commands, width);
+        return;
+    }
+    for mut fill in fills.iter_mut() {
+        fill.width = width;
+    }
+}
+
+fn bye_interaction(mut commands: Commands, bars: Query<Entity, With<HoldProgressBar>>) {
+    debug!("stopping {}", NAME);
+    commands.remove_resource::<HoldInteraction>();
+    for bar in bars.iter() {
+        commands.entity(bar).despawn_recursive();
     }
 }
 
 // helper functions
 
+fn spawn_progress_bar(commands: &mut Commands, width: Val) {
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(50.0),
+            top: Val::Percent(55.0),
+            margin: UiRect::left(Val::Px(-PROGRESS_BAR_WIDTH / 2.0)),
+            width: Val::Px(PROGRESS_BAR_WIDTH),
+            height: Val::Px(PROGRESS_BAR_HEIGHT),
+            ..default()
+        })
+        .insert(BackgroundColor(PROGRESS_BAR_BACKGROUND_COLOR))
+        .insert(HoldProgressBar)
+        .insert(Name::new("Hold Progress Bar"))
+        .with_children(|bar| {
+            bar.spawn(Node {
+                width,
+                height: Val::Percent(100.0),
+                ..default()
+            })
+            .insert(BackgroundColor(PROGRESS_BAR_COLOR))
+            .insert(HoldProgressFill);
+        });
+}
+
+/// continues holding the same target, starts over on anything else
+fn hold_step(
+    current: Option<&HoldInteraction>,
+    interaction_entity: Entity,
+    operator_entity: Entity,
+    duration: f32,
+    delta_secs: f32,
+) -> HoldInteraction {
+    let elapsed = current
+        .filter(|current| {
+            current.interaction_entity == interaction_entity
+                && current.operator_entity == operator_entity
+        })
+        .map_or(0.0, |current| current.elapsed);
+    HoldInteraction {
+        interaction_entity,
+        operator_entity,
+        elapsed: elapsed + delta_secs,
+        duration,
+    }
+}
+
 // tests
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
-    //use super::*;
+    use super::*;
+
+    #[test]
+    fn should_continue_holding_same_target_only() {
+        // given
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        let other = world.spawn_empty().id();
+        let operator = world.spawn_empty().id();
+
+        // when
+        let first = hold_step(None, target, operator, 2.0, 0.5);
+        let second = hold_step(Some(&first), target, operator, 2.0, 1.5);
+        let switched = hold_step(Some(&second), other, operator, 2.0, 0.5);
+
+        // then
+        assert_eq!(0.25, first.progress());
+        assert!(!first.is_complete());
+        assert!(second.is_complete());
+        assert_eq!(0.5, switched.elapsed);
+    }
+
+    #[test]
+    fn should_show_hold_progress_while_holding() {
+        // given
+        let mut app = App::new();
+        app.add_systems(Update, hold_progress_bar);
+        let target = app.world_mut().spawn_empty().id();
+        let operator = app.world_mut().spawn_empty().id();
+        app.insert_resource(HoldInteraction {
+            interaction_entity: target,
+            operator_entity: operator,
+            elapsed: 0.5,
+            duration: 2.0,
+        });
+
+        // when
+        app.update();
+
+        // then
+        let mut fills = app
+            .world_mut()
+            .query_filtered::<&Node, With<HoldProgressFill>>();
+        assert_eq!(Val::Percent(25.0), fills.single(app.world()).width);
+
+        // when
+        app.world_mut().remove_resource::<HoldInteraction>();
+        app.update();
+
+        // then
+        let mut bars = app
+            .world_mut()
+            .query_filtered::<Entity, With<HoldProgressBar>>();
+        assert!(bars.iter(app.world()).next().is_none());
+    }
 
     /*
     #[test]
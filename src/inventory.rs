@@ -4,9 +4,10 @@ use std::ops::Range;
 use bevy::app::Plugin;
 use bevy_inspector_egui::prelude::*;
 
+use crate::exfil::Operator;
 use crate::interaction::{Interact, InventoryInteracted};
 use crate::lock::Lock;
-use crate::loot::{DroppedLoot, Loot, LootCacheState, LootType};
+use crate::loot::{ContractItem, DroppedLoot, Loot, LootCacheState, LootType};
 use crate::raid::RaidState;
 use crate::wallet::StowMoney;
 use crate::AppState;
@@ -30,6 +31,7 @@ impl Plugin for InventoryPlugin {
             .add_event::<StowedLoot>()
             .add_event::<DropLoot>()
             .add_event::<InventoryAccessed>()
+            .add_event::<LootCacheOpened>()
             .add_systems(OnEnter(Raid), start_inventory_system)
             .add_systems(
                 Update,
@@ -56,6 +58,7 @@ fn inventory_interaction(
     backpack_query: Query<(Entity, &Parent), With<Inventory>>,
     mut inventory_interacted: EventWriter<InventoryInteracted>,
     mut inventory_opened: EventWriter<InventoryAccessed>,
+    mut loot_cache_opened: EventWriter<LootCacheOpened>,
 ) {
     for command in interaction_commands.read() {
         // filter for commands on Inventory entities only
@@ -79,6 +82,10 @@ fn inventory_interaction(
                             commands
                                 .entity(interaction_inventory)
                                 .insert(LootCacheState::Open);
+                            loot_cache_opened.send(LootCacheOpened {
+                                operator,
+                                inventory: interaction_inventory,
+                            });
                             inventory_opened.send(InventoryAccessed {
                                 operator,
                                 backpack,
@@ -159,6 +166,13 @@ pub struct InventoryAccessed {
     pub inventory: Entity,
 }
 
+/// a closed loot cache was opened for the first time
+#[derive(Event, Debug, PartialEq)]
+pub struct LootCacheOpened {
+    pub operator: Entity,
+    pub inventory: Entity,
+}
+
 // Systems
 fn start_inventory_system(mut _commands: Commands) {
     debug!("starting {}", NAME);
@@ -187,12 +201,27 @@ fn stow_loot_system(
     inventory_weapons: Query<(&Parent, &WeaponSlot), With<Loot>>,
     mut event: EventWriter<StowedLoot>,
     mut stow_money: EventWriter<StowMoney>,
+    contract_items: Query<(), With<ContractItem>>,
+    operators: Query<(), With<Operator>>,
 ) {
     debug!("updating stow listener");
 
     for c in command.read() {
         let inventory = c.stowing_entity;
 
+        let carried = |inventory: Entity| {
+            parents
+                .get(inventory)
+                .is_ok_and(|operator| operators.contains(operator.get()))
+        };
+        let carried_now = parents
+            .get(c.loot)
+            .is_ok_and(|current_inventory| carried(current_inventory.get()));
+        if contract_items.contains(c.loot) && carried_now && !carried(inventory) {
+            debug!("contract item {} stays with the operator", c.loot);
+            continue;
+        }
+
         let inventory_items: Vec<&ItemSlot> = inventory_items
             .iter()
             .filter(|ii| inventory == ii.0.get())
@@ -909,6 +938,43 @@ mod tests {
         assert_eq!(item_slot.unwrap().0, 1);
     }
 
+    #[test]
+    fn should_keep_contract_item_with_operator() {
+        // given
+        let mut app = App::new();
+        app.add_event::<StowLoot>();
+        app.add_event::<StowedLoot>();
+        app.add_event::<StowMoney>();
+        app.add_systems(Update, stow_loot_system);
+        let operator = app.world_mut().spawn(Operator).id();
+        let backpack = app
+            .world_mut()
+            .spawn((Inventory, ItemSlots(2)))
+            .set_parent(operator)
+            .id();
+        let cache = app.world_mut().spawn((Inventory, ItemSlots(2))).id();
+        let intel = app
+            .world_mut()
+            .spawn((Loot, ContractItem, ItemSlot(0)))
+            .set_parent(backpack)
+            .id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<StowLoot>>()
+            .send(StowLoot {
+                stowing_entity: cache,
+                loot: intel,
+                loot_type: LootType::Intel,
+            });
+        app.update();
+
+        // then
+        assert_eq!(backpack, app.world().get::<Parent>(intel).unwrap().get());
+        let stowed_loot_events = app.world().resource::<Events<StowedLoot>>();
+        assert!(stowed_loot_events.is_empty());
+    }
+
     #[test]
     fn should_drop_item_loot() {
         // given
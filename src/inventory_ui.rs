@@ -244,7 +244,7 @@ fn toggle_backpack_ui(
 
 // STARTING SYSTEMS
 
-fn startup_cursor_crosshair(
+pub(crate) fn startup_cursor_crosshair(
     mut commands: Commands,
     mut windows: Query<&mut Window, With<PrimaryWindow>>,
     crosshair: Query<Entity, With<Crosshair>>,
@@ -1174,7 +1174,7 @@ fn update_loadout_ui() {
 
 // SHUTDOWN SYSTEMS
 
-fn cleanup_cursor_crosshair(
+pub(crate) fn cleanup_cursor_crosshair(
     mut commands: Commands,
     mut windows: Query<&mut Window, With<PrimaryWindow>>,
     crosshair: Query<Entity, With<Crosshair>>,
@@ -0,0 +1,427 @@
+use bevy::app::Plugin;
+
+use crate::interaction::Interact;
+use crate::inventory_ui::{cleanup_cursor_crosshair, startup_cursor_crosshair};
+use crate::lock::{Lock, StillLocked, Unlocked};
+use crate::raid::RaidState;
+use crate::AppState;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "keypad";
+/// no lock code has more digits than this
+const MAX_CODE_DIGITS: usize = 6;
+const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
+const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
+const PRESSED_BUTTON: Color = Color::srgb(0.35, 0.75, 0.35);
+const DIGIT_KEYS: [(KeyCode, u8); 10] = [
+    (KeyCode::Digit0, 0),
+    (KeyCode::Digit1, 1),
+    (KeyCode::Digit2, 2),
+    (KeyCode::Digit3, 3),
+    (KeyCode::Digit4, 4),
+    (KeyCode::Digit5, 5),
+    (KeyCode::Digit6, 6),
+    (KeyCode::Digit7, 7),
+    (KeyCode::Digit8, 8),
+    (KeyCode::Digit9, 9),
+];
+
+// Plugin
+/// locked entities with a keypad are unlocked by entering the code of their lock
+pub struct KeypadPlugin;
+
+impl Plugin for KeypadPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CodeEntered>()
+            .add_systems(
+                Update,
+                (open_keypad).run_if(in_state(AppState::Raid).and(in_state(RaidState::Raid))),
+            )
+            .add_systems(
+                OnEnter(RaidState::AccessKeypad),
+                (startup_cursor_crosshair, start_keypad_ui)
+                    .chain()
+                    .run_if(resource_exists::<KeypadEntry>),
+            )
+            .add_systems(
+                Update,
+                (
+                    keypad_buttons,
+                    keypad_keys,
+                    update_keypad_display,
+                    check_code,
+                )
+                    .chain()
+                    .run_if(in_state(RaidState::AccessKeypad))
+                    .run_if(resource_exists::<KeypadEntry>),
+            )
+            .add_systems(
+                OnExit(RaidState::AccessKeypad),
+                (cleanup_cursor_crosshair, bye_keypad_ui).chain(),
+            );
+    }
+}
+
+// Components
+
+/// the `Lock` of this entity is opened with its code instead of a key
+#[derive(Component, Debug)]
+pub struct Keypad;
+
+#[derive(Component)]
+struct KeypadUI;
+
+#[derive(Component)]
+struct KeypadDisplay;
+
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+enum KeypadKey {
+    Digit(u8),
+    Clear,
+    Enter,
+    Close,
+}
+
+// Resources
+
+/// the keypad in use and the digits entered so far
+#[derive(Resource, Debug)]
+struct KeypadEntry {
+    keypad: Entity,
+    operator: Entity,
+    digits: String,
+}
+
+// Events
+
+/// an operator confirmed the digits entered on a keypad
+#[derive(Event, Debug, PartialEq)]
+pub struct CodeEntered {
+    pub keypad: Entity,
+    pub operator: Entity,
+    pub digits: String,
+}
+
+// Systems
+
+fn open_keypad(
+    mut commands: Commands,
+    mut interactions: EventReader<Interact>,
+    keypads: Query<(), (With<Keypad>, With<Lock>)>,
+    mut next_raid_state: ResMut<NextState<RaidState>>,
+) {
+    for interaction in interactions.read() {
+        if !keypads.contains(interaction.interaction_entity) {
+            continue;
+        }
+        debug!("{} of {} in use", NAME, interaction.interaction_entity);
+        commands.insert_resource(KeypadEntry {
+            keypad: interaction.interaction_entity,
+            operator: interaction.operator_entity,
+            digits: String::new(),
+        });
+        next_raid_state.set(RaidState::AccessKeypad);
+    }
+}
+
+fn start_keypad_ui(mut commands: Commands) {
+    debug!("starting {} ui", NAME);
+    let rows = [
+        [
+            KeypadKey::Digit(1),
+            KeypadKey::Digit(2),
+            KeypadKey::Digit(3),
+        ],
+        [
+            KeypadKey::Digit(4),
+            KeypadKey::Digit(5),
+            KeypadKey::Digit(6),
+        ],
+        [
+            KeypadKey::Digit(7),
+            KeypadKey::Digit(8),
+            KeypadKey::Digit(9),
+        ],
+        [KeypadKey::Clear, KeypadKey::Digit(0), KeypadKey::Enter],
+    ];
+    commands
+        .spawn(Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            padding: UiRect {
+                top: Val::Percent(10.),
+                ..default()
+            },
+            justify_self: JustifySelf::Center,
+            ..default()
+        })
+        .insert(KeypadUI)
+        .insert(Name::new("Keypad Layout"))
+        .with_children(|builder| {
+            builder
+                .spawn(Text::new(""))
+                .insert(TextFont {
+                    font_size: 40.0,
+                    ..default()
+                })
+                .insert(TextColor(Color::srgb(0.2, 0.9, 0.2)))
+                .insert(KeypadDisplay);
+            for row in rows {
+                builder
+                    .spawn(Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Row,
+                        ..default()
+                    })
+                    .with_children(|builder| {
+                        for key in row {
+                            spawn_key_button(builder, key);
+                        }
+                    });
+            }
+            spawn_key_button(builder, KeypadKey::Close);
+        });
+}
+
+#[allow(clippy::type_complexity)]
+fn keypad_buttons(
+    mut entry: ResMut<KeypadEntry>,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &KeypadKey),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut code_entered: EventWriter<CodeEntered>,
+    mut next_raid_state: ResMut<NextState<RaidState>>,
+) {
+    for (interaction, mut color, key) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                press_key(*key, &mut entry, &mut code_entered, &mut next_raid_state);
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
+
+/// digits, backspace to clear and enter work on the keyboard as well
+fn keypad_keys(
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut entry: ResMut<KeypadEntry>,
+    mut code_entered: EventWriter<CodeEntered>,
+    mut next_raid_state: ResMut<NextState<RaidState>>,
+) {
+    let pressed = DIGIT_KEYS
+        .iter()
+        .filter(|(key_code, _)| key_input.just_pressed(*key_code))
+        .map(|(_, digit)| KeypadKey::Digit(*digit))
+        .chain(
+            [
+                (KeyCode::Backspace, KeypadKey::Clear),
+                (KeyCode::Enter, KeypadKey::Enter),
+            ]
+            .into_iter()
+            .filter(|(key_code, _)| key_input.just_pressed(*key_code))
+            .map(|(_, key)| key),
+        )
+        .collect::<Vec<_>>();
+    for key in pressed {
+        press_key(key, &mut entry, &mut code_entered, &mut next_raid_state);
+    }
+}
+
+fn update_keypad_display(
+    entry: Res<KeypadEntry>,
+    display: Query<Entity, With<KeypadDisplay>>,
+    mut writer: TextUiWriter,
+) {
+    if !entry.is_changed() {
+        return;
+    }
+    if let Ok(display) = display.get_single() {
+        *writer.text(display, 0) = display_text(&entry.digits);
+    }
+}
+
+/// the right code opens the lock and closes the keypad, a wrong one starts over
+fn check_code(
+    mut commands: Commands,
+    mut code_entered: EventReader<CodeEntered>,
+    locks: Query<&Lock>,
+    mut entry: ResMut<KeypadEntry>,
+    mut unlocked: EventWriter<Unlocked>,
+    mut still_locked: EventWriter<StillLocked>,
+    mut next_raid_state: ResMut<NextState<RaidState>>,
+) {
+    for event in code_entered.read() {
+        let Ok(lock) = locks.get(event.keypad) else {
+            continue;
+        };
+        if is_code(&event.digits, lock.code) {
+            debug!("{} of {} unlocked", NAME, event.keypad);
+            commands.entity(event.keypad).remove::<Lock>();
+            unlocked.send(Unlocked {
+                unlocked_entity: event.keypad,
+                operator_entity: event.operator,
+            });
+            next_raid_state.set(RaidState::Raid);
+        } else {
+            debug!("{} of {}: wrong code", NAME, event.keypad);
+            still_locked.send(StillLocked {
+                still_locked_entity: event.keypad,
+                operator_entity: event.operator,
+            });
+            entry.digits.clear();
+        }
+    }
+}
+
+fn bye_keypad_ui(mut commands: Commands, ui: Query<Entity, With<KeypadUI>>) {
+    debug!("stopping {} ui", NAME);
+    commands.remove_resource::<KeypadEntry>();
+    for entity in ui.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// helper functions
+
+fn press_key(
+    key: KeypadKey,
+    entry: &mut KeypadEntry,
+    code_entered: &mut EventWriter<CodeEntered>,
+    next_raid_state: &mut NextState<RaidState>,
+) {
+    match key {
+        KeypadKey::Digit(digit) => {
+            if entry.digits.len() < MAX_CODE_DIGITS {
+                entry.digits.push_str(&digit.to_string());
+            }
+        }
+        KeypadKey::Clear => entry.digits.clear(),
+        KeypadKey::Enter => {
+            code_entered.send(CodeEntered {
+                keypad: entry.keypad,
+                operator: entry.operator,
+                digits: entry.digits.clone(),
+            });
+        }
+        KeypadKey::Close => next_raid_state.set(RaidState::Raid),
+    }
+}
+
+/// leading zeros count, a code of 42 is entered as 42 and not as 0042
+fn is_code(digits: &str, code: u32) -> bool {
+    !digits.is_empty() && digits == code.to_string()
+}
+
+fn display_text(digits: &str) -> String {
+    format!("{:_<width$}", digits, width = MAX_CODE_DIGITS)
+}
+
+fn key_label(key: KeypadKey) -> String {
+    match key {
+        KeypadKey::Digit(digit) => digit.to_string(),
+        KeypadKey::Clear => String::from("CLR"),
+        KeypadKey::Enter => String::from("OK"),
+        KeypadKey::Close => String::from("CLOSE"),
+    }
+}
+
+fn spawn_key_button(builder: &mut ChildBuilder, key: KeypadKey) {
+    builder
+        .spawn(Button)
+        .insert(Node {
+            width: Val::Px(80.),
+            height: Val::Px(60.),
+            margin: UiRect::all(Val::Px(5.)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .insert(BackgroundColor(NORMAL_BUTTON))
+        .insert(key)
+        .with_children(|parent| {
+            parent
+                .spawn(Text::new(key_label(key)))
+                .insert(TextFont {
+                    font_size: 30.0,
+                    ..default()
+                })
+                .insert(TextColor(Color::srgb(0.9, 0.9, 0.9)));
+        });
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn should_match_code_exactly() {
+        // then
+        assert!(is_code("4711", 4711));
+        assert!(!is_code("04711", 4711));
+        assert!(!is_code("471", 4711));
+        assert!(!is_code("", 0));
+        assert_eq!("47____", display_text("47"));
+    }
+
+    #[test]
+    fn should_unlock_with_right_code_only() {
+        // given
+        let mut app = App::new();
+        app.add_event::<CodeEntered>();
+        app.add_event::<Unlocked>();
+        app.add_event::<StillLocked>();
+        app.init_resource::<NextState<RaidState>>();
+        app.add_systems(Update, check_code);
+        let keypad = app.world_mut().spawn((Keypad, Lock { code: 4711 })).id();
+        let operator = app.world_mut().spawn_empty().id();
+        app.insert_resource(KeypadEntry {
+            keypad,
+            operator,
+            digits: String::from("1234"),
+        });
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<CodeEntered>>()
+            .send(CodeEntered {
+                keypad,
+                operator,
+                digits: String::from("1234"),
+            });
+        app.update();
+
+        // then
+        assert!(app.world().get::<Lock>(keypad).is_some());
+        assert!(app.world().resource::<KeypadEntry>().digits.is_empty());
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<CodeEntered>>()
+            .send(CodeEntered {
+                keypad,
+                operator,
+                digits: String::from("4711"),
+            });
+        app.update();
+
+        // then
+        assert!(app.world().get::<Lock>(keypad).is_none());
+        let unlocked_events = app.world().resource::<Events<Unlocked>>();
+        let mut unlocked_reader = unlocked_events.get_cursor();
+        assert_eq!(
+            vec![&Unlocked {
+                unlocked_entity: keypad,
+                operator_entity: operator,
+            }],
+            unlocked_reader.read(unlocked_events).collect::<Vec<_>>()
+        );
+    }
+}
@@ -64,9 +64,7 @@ pub enum LootType {
     FieldUpgrade,
     #[allow(dead_code)]
     KillStreak,
-    #[allow(dead_code)]
-    CircleDefense, // gasmask
-    #[allow(dead_code)]
+    CircleDefense,       // gasmask
     RadiationProtection, // radiation meds
     #[allow(dead_code)]
     LastStand, // self revive
@@ -80,7 +78,6 @@ pub enum LootType {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ItemType {
-    #[allow(dead_code)]
     Equipment, // example: vests
     Item,
 }
@@ -112,6 +109,10 @@ pub enum Rarity {
 #[allow(dead_code)]
 pub struct Stashable;
 
+/// objective item of a contract, once an operator carries it, it can't be put into another cache
+#[derive(Component, Debug)]
+pub struct ContractItem;
+
 /// uses can be subject to rng, not every use will progress usage.
 /// this is up to the system to decide not the component.
 /// the components is just a storage of the current usage state.
@@ -3,79 +3,131 @@ use active_missions::ActiveMissionsScreenPlugin;
 use armor::ArmorPlugin;
 use backpack_summary::BackpackSummaryPlugin;
 use bevy::prelude::*;
+use cargo_delivery::CargoDeliveryPlugin;
 use choose_location::ChooseLocationScreenPlugin;
+use combat_hud::CombatHudPlugin;
 use compass::CompassPlugin;
+use contract_menu::ContractMenuPlugin;
 use contracts::ContractsPlugin;
 use coordinates::CoordinatesPlugin;
 use damage::DamagePlugin;
 use death::DeathPlugin;
+use destroy_supplies::DestroySuppliesPlugin;
+use eliminate_hvt::EliminateHVTPlugin;
 use exfil::ExfilPlugin;
+use exfil_hot_zone::ExfilHotZonePlugin;
 use exfil_timers::ExfilTimersPlugin;
 use fake_level::FakeLevelPlugin;
 use first_person_controller::FirstPersonControllerPlugin;
 use flee::FleePlugin;
 use follow::FollowPlugin;
+use gas::GasPlugin;
 use health::HealthPlugin;
 use heightmap::HeightmapPlugin;
+use hunt_squad::HuntSquadPlugin;
+use infil::InfilPlugin;
 use interaction::InteractionPlugin;
 use inventory::InventoryPlugin;
 use inventory_testing::InventoryTestingPlugin;
 use inventory_ui::InventoryUIPlugin;
+use keypad::KeypadPlugin;
 use loading_screen::MatchLoadingScreenPlugin;
 use lock::LockPlugin;
 use loot::LootPlugin;
+use match_history::MatchHistoryPlugin;
+use match_history_screen::MatchHistoryScreenPlugin;
 use matchmake::{MatchmakeInProgressScreenPlugin, MatchmakeScreenPlugin};
 use mission_objective_screen::MissionObjectivesScreenPlugin;
 use out_of_bounds::OutOfBoundsPlugin;
+use personal_exfil::PersonalExfilPlugin;
+use player_hud::PlayerHudPlugin;
 use point_of_interest::PointOfInterestPlugin;
+use post_raid_screen::PostRaidScreenPlugin;
 use projectile::ProjectilePlugin;
+use radiation::RadiationPlugin;
 use raid::RaidPlugin;
+use raid_clock::RaidClockPlugin;
+use raid_end::RaidEndPlugin;
+use raid_event_log::RaidEventLogPlugin;
 use raid_summary::RaidSummaryPlugin;
+use raid_weapon_stash::RaidWeaponStashPlugin;
+use rescue_hostage::RescueHostagePlugin;
+use secure_intel::SecureIntelPlugin;
+use secure_nuclear_materials::SecureNuclearMaterialsPlugin;
+use signal_intelligence::SignalIntelligencePlugin;
 use skybox::SkyboxPlugin;
 use spawn::SpawnPlugin;
 use squad::SquadPlugin;
 use start_screen::StartScreenPlugin;
+use stash::StashPlugin;
+use vehicle::VehiclePlugin;
 use wallet::WalletPlugin;
 
 mod active_duty_confirmation;
 mod active_missions;
 mod armor;
 mod backpack_summary;
+mod cargo_delivery;
 mod choose_location;
+mod combat_hud;
 mod compass;
+mod contract_menu;
 mod contracts;
 mod coordinates;
 mod damage;
 mod death;
 mod deploy;
+mod destroy_supplies;
+mod eliminate_hvt;
 mod exfil;
+mod exfil_hot_zone;
 mod exfil_timers;
 mod fake_level;
 mod first_person_controller;
 mod flee;
 mod follow;
+mod gas;
 mod health;
 mod heightmap;
+mod hunt_squad;
+mod infil;
 mod interaction;
 mod inventory;
 mod inventory_testing;
 mod inventory_ui;
+mod keypad;
 mod loading_screen;
 mod loadout;
 mod lock;
 mod loot;
+mod match_history;
+mod match_history_screen;
 mod matchmake;
 mod mission_objective_screen;
 mod out_of_bounds;
+mod personal_exfil;
+mod player_hud;
 mod point_of_interest;
+mod post_raid_screen;
 mod projectile;
+mod radiation;
 mod raid;
+mod raid_clock;
+mod raid_end;
+mod raid_event_log;
 mod raid_summary;
+mod raid_weapon_stash;
+mod rescue_hostage;
+mod secure_intel;
+mod secure_nuclear_materials;
+mod signal_intelligence;
 mod skybox;
 mod spawn;
 mod squad;
 mod start_screen;
+mod stash;
 mod template_plugin;
+mod vehicle;
 mod wallet;
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash, States)]
@@ -86,6 +138,7 @@ pub enum AppState {
     #[default]
     LoadingScreen,
     Raid,
+    PostRaid,
 }
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash, States)]
@@ -98,6 +151,7 @@ pub enum MissionObjectives {
     Upgrades,
     LocationObjectives,
     Notes,
+    MatchHistory,
 }
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash, States)]
@@ -170,6 +224,36 @@ fn main() {
             LockPlugin,
             DeathPlugin,
             ProjectilePlugin,
+            CombatHudPlugin,
+            PlayerHudPlugin,
+        ))
+        .add_plugins((
+            GasPlugin,
+            RaidClockPlugin,
+            RaidEndPlugin,
+            PostRaidScreenPlugin,
+            RadiationPlugin,
+            InfilPlugin,
+            PersonalExfilPlugin,
+            ExfilHotZonePlugin,
+            StashPlugin,
+            MatchHistoryPlugin,
+            MatchHistoryScreenPlugin,
+            RaidEventLogPlugin,
+            EliminateHVTPlugin,
+            SecureIntelPlugin,
+            RescueHostagePlugin,
+        ))
+        .add_plugins((
+            DestroySuppliesPlugin,
+            KeypadPlugin,
+            RaidWeaponStashPlugin,
+            SignalIntelligencePlugin,
+            HuntSquadPlugin,
+            VehiclePlugin,
+            CargoDeliveryPlugin,
+            SecureNuclearMaterialsPlugin,
+            ContractMenuPlugin,
         ))
         .init_state::<AppState>()
         .add_systems(Update, close_on_esc)
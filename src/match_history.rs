@@ -0,0 +1,325 @@
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bevy::app::Plugin;
+use serde::{Deserialize, Serialize};
+
+use crate::choose_location::ChosenLocation;
+use crate::raid_summary::{RaidOutcome, RaidSummaries, RaidSummary};
+use crate::AppState::{self, PostRaid, Raid};
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "match history";
+const MATCH_HISTORY_FILE: &str = "match_history.jsonl";
+
+// Plugin
+/// every finished raid is appended as one JSON line to the match history file
+pub struct MatchHistoryPlugin;
+
+impl Plugin for MatchHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MatchHistoryFile>()
+            .add_systems(OnEnter(Raid), start_match_history)
+            .add_systems(OnEnter(PostRaid), record_match)
+            .add_systems(OnExit(AppState::PostRaid), bye_match_history);
+    }
+}
+
+// Components
+
+// Resources
+
+/// where the match history is kept, insert before the raid to override
+#[derive(Resource, Debug)]
+pub struct MatchHistoryFile(pub PathBuf);
+
+impl Default for MatchHistoryFile {
+    fn default() -> Self {
+        MatchHistoryFile(PathBuf::from(MATCH_HISTORY_FILE))
+    }
+}
+
+/// location and start of the running raid, the chosen location is gone once the raid started
+#[derive(Resource, Debug)]
+struct RaidStart {
+    location: String,
+    started: Duration,
+}
+
+/// one line of the match history file
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct MatchRecord {
+    /// when the raid ended, seconds since the unix epoch
+    #[serde(default)]
+    pub finished_at: u64,
+    pub location: String,
+    pub duration_secs: u64,
+    pub outcome: RaidOutcome,
+    /// enemy AI and operators killed
+    pub kills: u32,
+    pub contracts: u32,
+    /// extracted items and cash
+    pub extracted_value: u32,
+}
+
+impl MatchRecord {
+    fn new(finished_at: u64, location: String, duration: Duration, summary: &RaidSummary) -> Self {
+        let items_value: u32 = summary.extracted_items.iter().map(|item| item.value).sum();
+        MatchRecord {
+            finished_at,
+            location,
+            duration_secs: duration.as_secs(),
+            outcome: summary.outcome,
+            kills: summary.ai_kills + summary.operator_kills,
+            contracts: summary.contract_counter,
+            extracted_value: items_value.saturating_add(summary.extracted_cash),
+        }
+    }
+}
+
+/// totals of all raids on one location
+#[derive(Default, Debug, PartialEq)]
+pub struct LocationStats {
+    pub raids: u32,
+    pub exfils: u32,
+    pub kills: u32,
+    pub contracts: u32,
+    pub extracted_value: u64,
+    pub duration_secs: u64,
+}
+
+// Events
+
+// Systems
+
+/// runs in the same schedule as the raid start, which removes the chosen location with commands
+fn start_match_history(
+    mut commands: Commands,
+    time: Res<Time>,
+    location: Option<Res<ChosenLocation>>,
+) {
+    debug!("starting {}", NAME);
+    let location = location.map_or_else(|| String::from("Unknown"), |l| l.0.clone());
+    commands.insert_resource(RaidStart {
+        location,
+        started: time.elapsed(),
+    });
+}
+
+fn record_match(
+    time: Res<Time>,
+    history_file: Res<MatchHistoryFile>,
+    raid_start: Option<Res<RaidStart>>,
+    summaries: Option<Res<RaidSummaries>>,
+) {
+    let Some(raid_start) = raid_start else {
+        return;
+    };
+    let Some(summary) = summaries.as_ref().and_then(|summaries| {
+        summaries
+            .player
+            .and_then(|player| summaries.map.get(&player))
+    }) else {
+        return;
+    };
+    let finished_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since_epoch| since_epoch.as_secs());
+    let record = MatchRecord::new(
+        finished_at,
+        raid_start.location.clone(),
+        time.elapsed().saturating_sub(raid_start.started),
+        summary,
+    );
+    match append_record(&history_file.0, &record) {
+        Ok(()) => debug!("{}: recorded {:?}", NAME, record),
+        Err(error) => warn!("{}: could not write {:?}: {}", NAME, history_file.0, error),
+    }
+}
+
+fn bye_match_history(mut commands: Commands) {
+    debug!("stopping {}", NAME);
+    commands.remove_resource::<RaidStart>();
+}
+
+// helper functions
+
+fn append_record(path: &Path, record: &MatchRecord) -> io::Result<()> {
+    let line = serde_json::to_string(record)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)
+}
+
+/// all records of the match history, lines that can not be read are skipped
+pub fn read_records(path: &Path) -> Vec<MatchRecord> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(record) => Some(record),
+            Err(error) => {
+                warn!("{}: skipping line {:?}: {}", NAME, line, error);
+                None
+            }
+        })
+        .collect()
+}
+
+/// per location totals, sorted by location
+pub fn location_stats(records: &[MatchRecord]) -> BTreeMap<String, LocationStats> {
+    let mut stats: BTreeMap<String, LocationStats> = BTreeMap::new();
+    for record in records {
+        let location = stats.entry(record.location.clone()).or_default();
+        location.raids += 1;
+        if record.outcome == RaidOutcome::Exfilled {
+            location.exfils += 1;
+        }
+        location.kills += record.kills;
+        location.contracts += record.contracts;
+        location.extracted_value += u64::from(record.extracted_value);
+        location.duration_secs += record.duration_secs;
+    }
+    stats
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::raid_summary::ExtractedItem;
+
+    fn record(
+        location: &str,
+        outcome: RaidOutcome,
+        kills: u32,
+        extracted_value: u32,
+    ) -> MatchRecord {
+        MatchRecord {
+            finished_at: 1_700_000_000,
+            location: String::from(location),
+            duration_secs: 600,
+            outcome,
+            kills,
+            contracts: 1,
+            extracted_value,
+        }
+    }
+
+    #[test]
+    fn should_append_and_read_records() {
+        // given
+        let path = std::env::temp_dir().join(format!("match_history_{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let first = record("Farm", RaidOutcome::Exfilled, 3, 1_500);
+        let second = record("Lab", RaidOutcome::Killed, 0, 0);
+
+        // when
+        append_record(&path, &first).unwrap();
+        fs::write(
+            &path,
+            format!("{}not json\n", fs::read_to_string(&path).unwrap()),
+        )
+        .unwrap();
+        append_record(&path, &second).unwrap();
+        let records = read_records(&path);
+        fs::remove_file(&path).unwrap();
+
+        // then
+        assert_eq!(vec![first, second], records);
+    }
+
+    #[test]
+    fn should_aggregate_stats_per_location() {
+        // given
+        let records = vec![
+            record("Lab", RaidOutcome::Exfilled, 2, 1_000),
+            record("Farm", RaidOutcome::Killed, 1, 0),
+            record("Lab", RaidOutcome::MissingInAction, 3, 500),
+        ];
+
+        // when
+        let stats = location_stats(&records);
+
+        // then
+        assert_eq!(vec!["Farm", "Lab"], stats.keys().collect::<Vec<_>>());
+        assert_eq!(
+            &LocationStats {
+                raids: 2,
+                exfils: 1,
+                kills: 5,
+                contracts: 2,
+                extracted_value: 1_500,
+                duration_secs: 1_200,
+            },
+            stats.get("Lab").unwrap()
+        );
+    }
+
+    #[test]
+    fn should_record_finished_raid() {
+        // given
+        let path = std::env::temp_dir().join(format!("match_record_{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.insert_resource(MatchHistoryFile(path.clone()));
+        app.add_systems(Update, record_match);
+        app.insert_resource(RaidStart {
+            location: String::from("Farm"),
+            started: Duration::from_secs(5),
+        });
+        let player = app.world_mut().spawn_empty().id();
+        let mut summaries = RaidSummaries {
+            player: Some(player),
+            ..default()
+        };
+        summaries.map.insert(
+            player,
+            RaidSummary {
+                outcome: RaidOutcome::Exfilled,
+                ai_kills: 2,
+                operator_kills: 1,
+                contract_counter: 1,
+                extracted_items: vec![ExtractedItem {
+                    name: String::from("Gold Bar"),
+                    value: 50_000,
+                }],
+                extracted_cash: 1_500,
+                ..default()
+            },
+        );
+        app.insert_resource(summaries);
+
+        // when
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(125));
+        app.update();
+        let records = read_records(&path);
+        fs::remove_file(&path).unwrap();
+
+        // then
+        assert_eq!(1, records.len());
+        assert!(records[0].finished_at > 0);
+        assert_eq!(
+            MatchRecord {
+                finished_at: records[0].finished_at,
+                location: String::from("Farm"),
+                duration_secs: 120,
+                outcome: RaidOutcome::Exfilled,
+                kills: 3,
+                contracts: 1,
+                extracted_value: 51_500,
+            },
+            records[0]
+        );
+    }
+}
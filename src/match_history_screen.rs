@@ -0,0 +1,236 @@
+use crate::{
+    match_history::{location_stats, read_records, LocationStats, MatchHistoryFile},
+    AppState::{self, MissionObjectives},
+    ButtonTargetState,
+    MissionObjectives::{MatchHistory, Start},
+};
+use bevy::prelude::*;
+
+// --- Match History Screen START
+
+pub struct MatchHistoryScreenPlugin;
+
+impl Plugin for MatchHistoryScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(MissionObjectives(MatchHistory)),
+            start_match_history_screen,
+        )
+        .add_systems(
+            Update,
+            (update_match_history_screen).run_if(in_state(MissionObjectives(MatchHistory))),
+        )
+        .add_systems(
+            OnExit(MissionObjectives(MatchHistory)),
+            bye_match_history_screen,
+        );
+    }
+}
+
+#[derive(Resource)]
+struct MenuData {
+    match_history_screen_layout: Entity,
+}
+
+const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
+const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
+const PRESSED_BUTTON: Color = Color::srgb(0.35, 0.75, 0.35);
+
+fn start_match_history_screen(mut commands: Commands, history_file: Res<MatchHistoryFile>) {
+    debug!("starting match history screen");
+    let stats = location_stats(&read_records(&history_file.0));
+    let mut lines: Vec<String> = stats
+        .iter()
+        .map(|(location, stats)| stats_line(location, stats))
+        .collect();
+    if lines.is_empty() {
+        lines.push(String::from("NO RAIDS YET"));
+    }
+
+    // Layout
+    // Top-level grid (app frame)
+    let match_history_screen_layout = commands
+        .spawn(Node {
+            display: Display::Grid,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            grid_template_columns: vec![GridTrack::auto()],
+            grid_template_rows: vec![GridTrack::auto(), GridTrack::flex(1.0), GridTrack::px(20.)],
+            ..default()
+        })
+        .insert(Name::new("Match History Layout"))
+        .with_children(|builder| {
+            // Header
+            builder
+                .spawn(Node {
+                    display: Display::Grid,
+                    justify_items: JustifyItems::Center,
+                    padding: UiRect::all(Val::Px(12.0)),
+                    ..default()
+                })
+                .insert(Name::new("Header"))
+                .with_children(|builder| {
+                    spawn_nested_text_bundle(builder, 40.0, "MATCH HISTORY");
+                    spawn_nested_text_bundle(builder, 10.0, "");
+                    for line in lines.iter() {
+                        spawn_nested_text_bundle(builder, 20.0, line);
+                    }
+                });
+            // Main
+            builder
+                .spawn(Node {
+                    display: Display::Grid,
+                    justify_items: JustifyItems::Center,
+                    padding: UiRect::all(Val::Px(12.0)),
+                    ..default()
+                })
+                .insert(Name::new("Main"))
+                .with_children(|builder| {
+                    let back_name = Name::new("BACK");
+                    spawn_button_bundle(
+                        builder,
+                        back_name.clone(),
+                        back_name.as_str(),
+                        ButtonTargetState(MissionObjectives(Start)),
+                    );
+                });
+        })
+        .id();
+
+    // insert resource
+    commands.insert_resource(MenuData {
+        match_history_screen_layout,
+    });
+}
+
+#[allow(clippy::type_complexity)]
+fn update_match_history_screen(
+    mut next_state: ResMut<NextState<AppState>>,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &ButtonTargetState),
+        (Changed<Interaction>, With<Button>),
+    >,
+) {
+    debug!("updating match history screen");
+    for (interaction, mut color, target_state) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                debug!("button pressed");
+                *color = PRESSED_BUTTON.into();
+                next_state.set(target_state.0.clone());
+            }
+            Interaction::Hovered => {
+                debug!("button hovered");
+                *color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                debug!("button normal");
+                *color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn bye_match_history_screen(mut commands: Commands, menu_data: Res<MenuData>) {
+    debug!("bye match history screen!");
+    commands
+        .entity(menu_data.match_history_screen_layout)
+        .despawn_recursive();
+}
+
+// helper functions
+
+fn stats_line(location: &str, stats: &LocationStats) -> String {
+    let average_secs = stats.duration_secs / u64::from(stats.raids.max(1));
+    format!(
+        "{}: RAIDS {} EXFILS {} KILLS {} CONTRACTS {} EXTRACTED ${} AVG {}:{:02}",
+        location,
+        stats.raids,
+        stats.exfils,
+        stats.kills,
+        stats.contracts,
+        stats.extracted_value,
+        average_secs / 60,
+        average_secs % 60
+    )
+}
+
+fn spawn_nested_text_bundle(builder: &mut ChildBuilder, font_size: f32, text: &str) {
+    builder
+        .spawn(Text::new(text))
+        .insert(TextFont {
+            font_size,
+            ..default()
+        })
+        .insert(TextColor(Color::srgb(0.9, 0.9, 0.9)));
+}
+
+fn spawn_button_bundle(
+    builder: &mut ChildBuilder,
+    button_name_component: Name,
+    button_text: &str,
+    button_target_state: ButtonTargetState,
+) {
+    builder
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .insert(button_name_component.clone())
+        .with_children(|parent| {
+            parent
+                .spawn(Button)
+                .insert(Node {
+                    width: Val::Px(150.),
+                    height: Val::Px(110.),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .insert(BackgroundColor(NORMAL_BUTTON))
+                .insert(button_name_component)
+                .with_children(|parent| {
+                    parent
+                        .spawn(Text::new(button_text))
+                        .insert(TextFont {
+                            font_size: 40.0,
+                            ..default()
+                        })
+                        .insert(TextColor(Color::srgb(0.9, 0.9, 0.9)));
+                })
+                .insert(button_target_state);
+        });
+}
+// --- Match History Screen STOP
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn should_show_location_stats() {
+        // given
+        let stats = LocationStats {
+            raids: 2,
+            exfils: 1,
+            kills: 5,
+            contracts: 2,
+            extracted_value: 1_550,
+            duration_secs: 1_290,
+        };
+
+        // when
+        let line = stats_line("Lab", &stats);
+
+        // then
+        assert_eq!(
+            "Lab: RAIDS 2 EXFILS 1 KILLS 5 CONTRACTS 2 EXTRACTED $1550 AVG 10:45",
+            line
+        );
+    }
+}
@@ -30,6 +30,7 @@ struct MissionObjectiveMenuData {
     upgrades_button_entity: Entity,
     location_objectives_button_entity: Entity,
     notes_button_entity: Entity,
+    match_history_button_entity: Entity,
 }
 
 const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
@@ -182,11 +183,48 @@ fn start_mission_objectives_screen(mut commands: Commands) {
         })
         .id();
 
+    let match_history_button_entity = commands
+        .spawn(Node {
+            // center button
+            width: Val::Percent(140.),
+            height: Val::Percent(120.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(Button)
+                .insert(Node {
+                    width: Val::Px(220.),
+                    height: Val::Px(110.),
+                    // horizontally center child text
+                    justify_content: JustifyContent::Center,
+                    // vertically center child text
+                    align_items: AlignItems::Center,
+                    // TODO: redo
+                    //background_color: NORMAL_BUTTON.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn(Text::new("Match History"))
+                        .insert(TextFont {
+                            font_size: 40.0,
+                            ..default()
+                        })
+                        .insert(TextColor(Color::srgb(0.9, 0.9, 0.9)));
+                })
+                .insert(ButtonTargetState(MissionObjectives(MatchHistory)));
+        })
+        .id();
+
     commands.insert_resource(MissionObjectiveMenuData {
         missions_button_entity,
         upgrades_button_entity,
         location_objectives_button_entity,
         notes_button_entity,
+        match_history_button_entity,
     });
 
     commands
@@ -204,6 +242,10 @@ fn start_mission_objectives_screen(mut commands: Commands) {
     commands
         .entity(notes_button_entity)
         .insert(Name::new("Notes Button"));
+
+    commands
+        .entity(match_history_button_entity)
+        .insert(Name::new("Match History Button"));
 }
 
 #[allow(clippy::type_complexity)]
@@ -248,4 +290,7 @@ fn bye_mission_objective_screen(mut commands: Commands, menu_data: Res<MissionOb
     commands
         .entity(menu_data.notes_button_entity)
         .despawn_recursive();
+    commands
+        .entity(menu_data.match_history_button_entity)
+        .despawn_recursive();
 }
@@ -3,6 +3,7 @@ use core::fmt;
 use bevy::app::Plugin;
 
 use crate::exfil::Operator;
+use crate::infil::Seated;
 use crate::AppState;
 use crate::AppState::Raid;
 use bevy::prelude::*;
@@ -107,7 +108,7 @@ fn update_out_of_bounds_system(
             Option<&mut OutOfBounds>,
             Option<&mut Bounds>,
         ),
-        (With<Operator>, With<GlobalTransform>),
+        (With<Operator>, With<GlobalTransform>, Without<Seated>),
     >,
 ) {
     debug!("updating {}", NAME);
@@ -0,0 +1,225 @@
+use bevy::app::Plugin;
+
+use crate::exfil::{
+    ExfilArea, ExfilCooledDown, ExfilDurations, ExfilState, Operator, PrivateExfil,
+};
+use crate::first_person_controller::PlayerControlled;
+use crate::inventory::Inventory;
+use crate::loot::{Loot, LootType};
+use crate::squad::SquadId;
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "personal exfil";
+
+// Plugin
+/// consumable that calls a private exfil helicopter to the position of the operator
+pub struct PersonalExfilPlugin;
+
+impl Plugin for PersonalExfilPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<UsePersonalExfil>()
+            .add_event::<PersonalExfilUsed>()
+            .add_systems(OnEnter(Raid), start_personal_exfil)
+            .add_systems(
+                Update,
+                (
+                    player_uses_personal_exfil,
+                    use_personal_exfil,
+                    personal_exfil_cooled_down,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_personal_exfil);
+    }
+}
+
+// Components
+
+/// marks a `LootType::CircleDefense` item as personal exfil, it works inside the gas as well
+#[derive(Component, Debug)]
+pub struct PersonalExfil;
+
+/// exfil area spawned by a personal exfil, gone after the exfil cooled down
+#[derive(Component, Debug)]
+struct PersonalExfilArea;
+
+// Resources
+
+// Events
+
+/// command to use a personal exfil from the backpack
+#[derive(Event, Debug, PartialEq)]
+pub struct UsePersonalExfil {
+    pub operator: Entity,
+}
+
+#[derive(Event, Debug, PartialEq)]
+pub struct PersonalExfilUsed {
+    pub operator: Entity,
+    pub exfil_area: Entity,
+}
+
+// Systems
+fn start_personal_exfil(mut _commands: Commands) {
+    debug!("starting {}", NAME);
+}
+
+fn player_uses_personal_exfil(
+    key_input: Res<ButtonInput<KeyCode>>,
+    player: Query<Entity, (With<PlayerControlled>, With<Operator>)>,
+    mut use_exfil: EventWriter<UsePersonalExfil>,
+) {
+    if key_input.just_released(KeyCode::KeyX) {
+        if let Ok(operator) = player.get_single() {
+            use_exfil.send(UsePersonalExfil { operator });
+        }
+    }
+}
+
+/// uses up one personal exfil of the backpack and spawns a private exfil area right where the
+/// operator stands
+#[allow(clippy::type_complexity)]
+fn use_personal_exfil(
+    mut commands: Commands,
+    mut use_exfil: EventReader<UsePersonalExfil>,
+    operators: Query<(&GlobalTransform, &SquadId), With<Operator>>,
+    inventories: Query<&Parent, With<Inventory>>,
+    loot: Query<(Entity, &Parent, &LootType), (With<Loot>, With<PersonalExfil>)>,
+    mut used: EventWriter<PersonalExfilUsed>,
+) {
+    for event in use_exfil.read() {
+        let Ok((transform, squad_id)) = operators.get(event.operator) else {
+            continue;
+        };
+        let personal_exfil = loot.iter().find(|(_, backpack, loot_type)| {
+            **loot_type == LootType::CircleDefense
+                && inventories
+                    .get(backpack.get())
+                    .is_ok_and(|owner| owner.get() == event.operator)
+        });
+        let Some((personal_exfil, _, _)) = personal_exfil else {
+            debug!("operator {} has no personal exfil", event.operator);
+            continue;
+        };
+        debug!("operator {} used a personal exfil", event.operator);
+        commands.entity(personal_exfil).despawn_recursive();
+        let exfil_area = commands
+            .spawn(Transform::from_translation(transform.translation()))
+            .insert(Name::new("Personal Exfil"))
+            .insert(ExfilArea(format!("Personal Exfil {}", event.operator)))
+            .insert(PrivateExfil {
+                squad: squad_id.clone(),
+                caller: event.operator,
+            })
+            .insert(personal_exfil_durations())
+            .insert(PersonalExfilArea)
+            .id();
+        used.send(PersonalExfilUsed {
+            operator: event.operator,
+            exfil_area,
+        });
+    }
+}
+
+fn personal_exfil_cooled_down(
+    mut commands: Commands,
+    mut cooled_down: EventReader<ExfilCooledDown>,
+    areas: Query<Entity, With<PersonalExfilArea>>,
+) {
+    for event in cooled_down.read() {
+        if let Ok(area) = areas.get(event.exfil_entity) {
+            debug!("personal exfil {} is done", area);
+            commands.entity(area).despawn_recursive();
+        }
+    }
+}
+
+fn bye_personal_exfil(mut commands: Commands, areas: Query<Entity, With<PersonalExfilArea>>) {
+    debug!("stopping {}", NAME);
+    for area in areas.iter() {
+        commands.entity(area).despawn_recursive();
+    }
+}
+
+// helper functions
+
+/// no waiting around, the helicopter is on its way right away and leaves sooner
+fn personal_exfil_durations() -> ExfilDurations {
+    ExfilDurations {
+        secs: [
+            (ExfilState::Called, 0.0),
+            (ExfilState::EnteredAO, 0.0),
+            (ExfilState::Spawned, 0.0),
+            (ExfilState::Approached, 3.0),
+            (ExfilState::Descended, 2.0),
+            (ExfilState::LandingHover, 0.0),
+            (ExfilState::TouchedDown, 1.0),
+            (ExfilState::BoardingHold, 5.0),
+            (ExfilState::TookOff, 1.0),
+            (ExfilState::Climbed, 1.0),
+            (ExfilState::Cruised, 2.0),
+            (ExfilState::ExitedAO, 0.0),
+            (ExfilState::Cooldown, 0.0),
+        ]
+        .into_iter()
+        .collect(),
+    }
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn should_spawn_private_exfil_area_at_operator() {
+        // given
+        let mut app = App::new();
+        app.add_event::<UsePersonalExfil>();
+        app.add_event::<PersonalExfilUsed>();
+        app.add_systems(Update, use_personal_exfil);
+        let operator = app
+            .world_mut()
+            .spawn((
+                Operator,
+                SquadId(111),
+                GlobalTransform::from_xyz(3.0, 0.0, -7.0),
+            ))
+            .id();
+        let backpack = app.world_mut().spawn(Inventory).set_parent(operator).id();
+        let personal_exfil = app
+            .world_mut()
+            .spawn((Loot, LootType::CircleDefense, PersonalExfil))
+            .set_parent(backpack)
+            .id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<UsePersonalExfil>>()
+            .send(UsePersonalExfil { operator });
+        app.update();
+
+        // then
+        assert!(app.world().get::<Loot>(personal_exfil).is_none());
+        let used_events = app.world().resource::<Events<PersonalExfilUsed>>();
+        let mut used_reader = used_events.get_cursor();
+        let used: Vec<&PersonalExfilUsed> = used_reader.read(used_events).collect();
+        assert_eq!(1, used.len());
+        let exfil_area = used[0].exfil_area;
+        assert_eq!(
+            Vec3::new(3.0, 0.0, -7.0),
+            app.world()
+                .get::<Transform>(exfil_area)
+                .unwrap()
+                .translation
+        );
+        let private = app.world().get::<PrivateExfil>(exfil_area).unwrap();
+        assert_eq!(SquadId(111), private.squad);
+        assert_eq!(operator, private.caller);
+    }
+}
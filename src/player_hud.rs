@@ -0,0 +1,271 @@
+use bevy::app::Plugin;
+
+use crate::armor::Armor;
+use crate::backpack_summary::{BackpackSummary, BackpackSummaryUpdate};
+use crate::exfil::Operator;
+use crate::first_person_controller::PlayerControlled;
+use crate::health::Health;
+use crate::wallet::Wallet;
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "player hud";
+/// values of a fresh operator, bars are relative to them
+const MAX_HEALTH: i32 = 100;
+const MAX_ARMOR: i32 = 100;
+const BAR_WIDTH: f32 = 150.0;
+const BAR_HEIGHT: f32 = 8.0;
+/// seconds the backpack value counts up/down to its new value
+const BACKPACK_ANIMATION_SECS: f32 = 0.5;
+const BAR_BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+const HEALTH_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const ARMOR_COLOR: Color = Color::srgb(0.2, 0.6, 1.0);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+
+// Plugin
+/// in raid HUD of the player: health and plate bars, cash and backpack value
+pub struct PlayerHudPlugin;
+
+impl Plugin for PlayerHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_player_hud)
+            .add_systems(
+                Update,
+                (
+                    update_health_bar,
+                    update_armor_bar,
+                    update_cash_label,
+                    backpack_summary_updated,
+                    animate_backpack_value,
+                )
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_player_hud);
+    }
+}
+
+// Components
+
+#[derive(Component)]
+struct PlayerHudUI;
+
+#[derive(Component)]
+struct HealthBar;
+
+#[derive(Component)]
+struct ArmorBar;
+
+#[derive(Component)]
+struct CashLabel;
+
+#[derive(Component)]
+struct BackpackValueLabel;
+
+/// counts the backpack label from the old to the new value
+#[derive(Component)]
+struct BackpackValueAnimation {
+    from: u32,
+    to: u32,
+    timer: Timer,
+}
+
+// Resources
+
+// Events
+
+// Systems
+fn start_player_hud(mut commands: Commands) {
+    debug!("starting {}", NAME);
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            bottom: Val::Px(10.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        })
+        .insert(PlayerHudUI)
+        .insert(Name::new("Player HUD"))
+        .with_children(|parent| {
+            spawn_bar(parent, ARMOR_COLOR, ArmorBar);
+            spawn_bar(parent, HEALTH_COLOR, HealthBar);
+            parent
+                .spawn(Text::new(cash_text(0)))
+                .insert(TextFont {
+                    font_size: 12.0,
+                    ..default()
+                })
+                .insert(TextColor(TEXT_COLOR))
+                .insert(CashLabel);
+            parent
+                .spawn(Text::new(backpack_text(0)))
+                .insert(TextFont {
+                    font_size: 12.0,
+                    ..default()
+                })
+                .insert(TextColor(TEXT_COLOR))
+                .insert(BackpackValueLabel);
+        });
+}
+
+#[allow(clippy::type_complexity)]
+fn update_health_bar(
+    player: Query<&Health, (With<PlayerControlled>, Changed<Health>)>,
+    mut bar: Query<&mut Node, With<HealthBar>>,
+) {
+    if let (Ok(health), Ok(mut node)) = (player.get_single(), bar.get_single_mut()) {
+        node.width = Val::Percent(bar_percent(health.0, MAX_HEALTH));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_armor_bar(
+    player: Query<&Armor, (With<PlayerControlled>, Changed<Armor>)>,
+    mut bar: Query<&mut Node, With<ArmorBar>>,
+) {
+    if let (Ok(armor), Ok(mut node)) = (player.get_single(), bar.get_single_mut()) {
+        node.width = Val::Percent(bar_percent(armor.0, MAX_ARMOR));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_cash_label(
+    player: Query<&Wallet, (With<PlayerControlled>, Changed<Wallet>)>,
+    label: Query<Entity, With<CashLabel>>,
+    mut writer: TextUiWriter,
+) {
+    if let (Ok(wallet), Ok(label)) = (player.get_single(), label.get_single()) {
+        *writer.text(label, 0) = cash_text(wallet.money);
+    }
+}
+
+/// starts (or restarts) the backpack value animation, if the update belongs to the player
+fn backpack_summary_updated(
+    mut commands: Commands,
+    mut updates: EventReader<BackpackSummaryUpdate>,
+    player: Query<Entity, (With<PlayerControlled>, With<Operator>)>,
+    label: Query<Entity, With<BackpackValueLabel>>,
+) {
+    let (Ok(player), Ok(label)) = (player.get_single(), label.get_single()) else {
+        return;
+    };
+    for update in updates.read().filter(|u| u.entity == player) {
+        commands.entity(label).insert(BackpackValueAnimation {
+            from: update.old_value,
+            to: update.new_value,
+            timer: Timer::from_seconds(BACKPACK_ANIMATION_SECS, TimerMode::Once),
+        });
+    }
+}
+
+fn animate_backpack_value(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut labels: Query<(Entity, &mut BackpackValueAnimation)>,
+    player: Query<&BackpackSummary, With<PlayerControlled>>,
+    mut writer: TextUiWriter,
+) {
+    for (label, mut animation) in labels.iter_mut() {
+        animation.timer.tick(time.delta());
+        let value = if animation.timer.finished() {
+            commands.entity(label).remove::<BackpackValueAnimation>();
+            // the component is the source of truth once the animation is done
+            player
+                .get_single()
+                .map(|summary| summary.0)
+                .unwrap_or(animation.to)
+        } else {
+            animated_value(animation.from, animation.to, animation.timer.fraction())
+        };
+        *writer.text(label, 0) = backpack_text(value);
+    }
+}
+
+fn bye_player_hud(mut commands: Commands, hud: Query<Entity, With<PlayerHudUI>>) {
+    debug!("stopping {}", NAME);
+    for entity in hud.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// helper functions
+
+fn spawn_bar(parent: &mut ChildBuilder, color: Color, marker: impl Component) {
+    parent
+        .spawn(Node {
+            width: Val::Px(BAR_WIDTH),
+            height: Val::Px(BAR_HEIGHT),
+            ..default()
+        })
+        .insert(BackgroundColor(BAR_BACKGROUND_COLOR))
+        .with_children(|bar| {
+            bar.spawn(Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            })
+            .insert(BackgroundColor(color))
+            .insert(marker);
+        });
+}
+
+fn bar_percent(value: i32, max: i32) -> f32 {
+    (value as f32 / max as f32 * 100.0).clamp(0.0, 100.0)
+}
+
+fn animated_value(from: u32, to: u32, fraction: f32) -> u32 {
+    (from as f32 + (to as f32 - from as f32) * fraction.clamp(0.0, 1.0)).round() as u32
+}
+
+fn cash_text(money: u32) -> String {
+    format!("$ {}", money)
+}
+
+fn backpack_text(value: u32) -> String {
+    format!("backpack $ {}", value)
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn should_clamp_bar_percent() {
+        assert_eq!(50.0, bar_percent(50, 100));
+        assert_eq!(0.0, bar_percent(-10, 100));
+        assert_eq!(100.0, bar_percent(150, 100));
+    }
+
+    #[test]
+    fn should_animate_between_old_and_new_value() {
+        assert_eq!(100, animated_value(100, 300, 0.0));
+        assert_eq!(200, animated_value(100, 300, 0.5));
+        assert_eq!(300, animated_value(100, 300, 1.0));
+        assert_eq!(200, animated_value(300, 100, 0.5));
+    }
+
+    #[test]
+    fn should_resize_health_bar_on_change() {
+        // given
+        let mut app = App::new();
+        app.add_systems(Update, update_health_bar);
+        let player = app.world_mut().spawn((PlayerControlled, Health(100))).id();
+        let bar = app.world_mut().spawn((Node::default(), HealthBar)).id();
+        app.update();
+
+        // when
+        app.world_mut().get_mut::<Health>(player).unwrap().0 = 25;
+        app.update();
+
+        // then
+        assert_eq!(
+            Val::Percent(25.0),
+            app.world().get::<Node>(bar).unwrap().width
+        );
+    }
+}
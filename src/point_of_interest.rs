@@ -1,5 +1,7 @@
 use bevy::app::Plugin;
+use bevy::utils::HashSet;
 
+use crate::exfil::Operator;
 use crate::AppState;
 use crate::AppState::Raid;
 use bevy::prelude::*;
@@ -12,27 +14,75 @@ pub struct PointOfInterestPlugin;
 
 impl Plugin for PointOfInterestPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(Raid), start_poi_system)
-            .add_systems(Update, (update_poi_system).run_if(in_state(AppState::Raid)))
+        app.add_event::<PointOfInterestVisited>()
+            .add_systems(OnEnter(Raid), start_poi_system)
+            .add_systems(
+                Update,
+                (update_poi_system, visit_points_of_interest).run_if(in_state(AppState::Raid)),
+            )
             .add_systems(OnExit(AppState::Raid), bye_poi_system);
     }
 }
 
 // Components
 
+/// named place of the map, visited once an operator comes within the radius
+#[derive(Component, Debug)]
+pub struct PointOfInterest {
+    pub name: String,
+    pub radius: f32,
+}
+
 // Resources
 
+/// operator and point of interest pairs that were visited already
+#[derive(Resource, Default)]
+struct VisitedPointsOfInterest(HashSet<(Entity, Entity)>);
+
 // Events
 
+/// sent only for the first visit of an operator
+#[derive(Event, Debug, PartialEq)]
+pub struct PointOfInterestVisited {
+    pub operator: Entity,
+    pub poi: Entity,
+}
+
 // Systems
-fn start_poi_system(mut _commands: Commands) {
+fn start_poi_system(mut commands: Commands) {
     debug!("starting {}", NAME);
+    commands.insert_resource(VisitedPointsOfInterest::default());
 }
 fn update_poi_system() {
     debug!("updating {}", NAME);
 }
-fn bye_poi_system(mut _commands: Commands) {
+
+fn visit_points_of_interest(
+    visited: Option<ResMut<VisitedPointsOfInterest>>,
+    pois: Query<(Entity, &PointOfInterest, &GlobalTransform)>,
+    operators: Query<(Entity, &GlobalTransform), With<Operator>>,
+    mut poi_visited: EventWriter<PointOfInterestVisited>,
+) {
+    let Some(mut visited) = visited else {
+        return;
+    };
+    for (poi, point_of_interest, poi_transform) in pois.iter() {
+        for (operator, operator_transform) in operators.iter() {
+            let distance = poi_transform
+                .translation()
+                .xz()
+                .distance(operator_transform.translation().xz());
+            if distance <= point_of_interest.radius && visited.0.insert((operator, poi)) {
+                debug!("operator {} visited {}", operator, point_of_interest.name);
+                poi_visited.send(PointOfInterestVisited { operator, poi });
+            }
+        }
+    }
+}
+
+fn bye_poi_system(mut commands: Commands) {
     debug!("stopping {}", NAME);
+    commands.remove_resource::<VisitedPointsOfInterest>();
 }
 
 // helper functions
@@ -41,24 +91,42 @@ fn bye_poi_system(mut _commands: Commands) {
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
-    //use super::*;
+    use super::*;
 
-    /*
     #[test]
-    fn should_test_something() {
+    fn should_visit_point_of_interest_once() {
         // given
-        //let mut _app = App::new();
+        let mut app = App::new();
+        app.add_event::<PointOfInterestVisited>();
+        app.init_resource::<VisitedPointsOfInterest>();
+        app.add_systems(Update, visit_points_of_interest);
+        let poi = app
+            .world_mut()
+            .spawn((
+                PointOfInterest {
+                    name: String::from("Depot"),
+                    radius: 5.0,
+                },
+                GlobalTransform::from_xyz(10.0, 0.0, 0.0),
+            ))
+            .id();
+        let operator = app
+            .world_mut()
+            .spawn((Operator, GlobalTransform::from_xyz(7.0, 1.0, 0.0)))
+            .id();
+        app.world_mut()
+            .spawn((Operator, GlobalTransform::from_xyz(0.0, 1.0, 0.0)));
 
         // when
-        //app.add_event::<HealthDamageReceived>();
-        //app.add_systems(Update, damage_received_listener);
-        //let entity = app.borrow_mut().world.spawn(Health(100)).id();
-        //app.borrow_mut().world.resource_mut::<Events<HealthDamageReceived>>().send(HealthDamageReceived { entity, damage: 10 });
-        //app.update();
+        app.update();
+        app.update();
 
         // then
-        //assert!(app.world.get::<Health>(entity).is_some());
-        //assert_eq!(app.world.get::<Health>(entity).unwrap().0, 90);
+        let visited_events = app.world().resource::<Events<PointOfInterestVisited>>();
+        let mut visited_reader = visited_events.get_cursor();
+        assert_eq!(
+            vec![&PointOfInterestVisited { operator, poi }],
+            visited_reader.read(visited_events).collect::<Vec<_>>()
+        );
     }
-    */
 }
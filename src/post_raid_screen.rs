@@ -0,0 +1,239 @@
+use crate::{
+    raid_summary::{RaidOutcome, RaidSummaries, RaidSummary},
+    stash::RaidSummaryConfirmed,
+    AppState::{self, *},
+    ButtonTargetState,
+};
+use bevy::prelude::*;
+
+// --- Post Raid Screen START
+
+pub struct PostRaidScreenPlugin;
+
+impl Plugin for PostRaidScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(PostRaid), start_post_raid_screen)
+            .add_systems(Update, (update_post_raid_screen).run_if(in_state(PostRaid)))
+            .add_systems(OnExit(PostRaid), bye_post_raid_screen);
+    }
+}
+
+#[derive(Resource)]
+struct MenuData {
+    post_raid_screen_layout: Entity,
+}
+
+const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
+const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
+const PRESSED_BUTTON: Color = Color::srgb(0.35, 0.75, 0.35);
+
+fn start_post_raid_screen(mut commands: Commands, summaries: Option<Res<RaidSummaries>>) {
+    debug!("starting post raid screen");
+    let summary = summaries.as_ref().and_then(|summaries| {
+        summaries
+            .player
+            .and_then(|player| summaries.map.get(&player))
+    });
+    let lines = summary.map_or_else(Vec::new, summary_lines);
+
+    // Layout
+    // Top-level grid (app frame)
+    let post_raid_screen_layout = commands
+        .spawn(Node {
+            display: Display::Grid,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            grid_template_columns: vec![GridTrack::auto()],
+            grid_template_rows: vec![GridTrack::auto(), GridTrack::flex(1.0), GridTrack::px(20.)],
+            ..default()
+        })
+        .insert(Name::new("Post Raid Layout"))
+        .with_children(|builder| {
+            // Header
+            builder
+                .spawn(Node {
+                    display: Display::Grid,
+                    justify_items: JustifyItems::Center,
+                    padding: UiRect::all(Val::Px(12.0)),
+                    ..default()
+                })
+                .insert(Name::new("Header"))
+                .with_children(|builder| {
+                    spawn_nested_text_bundle(builder, 40.0, "RAID OVER");
+                    spawn_nested_text_bundle(builder, 10.0, "");
+                    for line in lines.iter() {
+                        spawn_nested_text_bundle(builder, 20.0, line);
+                    }
+                });
+            // Main
+            builder
+                .spawn(Node {
+                    display: Display::Grid,
+                    justify_items: JustifyItems::Center,
+                    padding: UiRect::all(Val::Px(12.0)),
+                    ..default()
+                })
+                .insert(Name::new("Main"))
+                .with_children(|builder| {
+                    let confirm_name = Name::new("CONFIRM");
+                    spawn_button_bundle(
+                        builder,
+                        confirm_name.clone(),
+                        confirm_name.as_str(),
+                        ButtonTargetState(StartScreen),
+                    );
+                });
+        })
+        .id();
+
+    // insert resource
+    commands.insert_resource(MenuData {
+        post_raid_screen_layout,
+    });
+}
+
+#[allow(clippy::type_complexity)]
+fn update_post_raid_screen(
+    mut next_state: ResMut<NextState<AppState>>,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &ButtonTargetState),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut confirmed: EventWriter<RaidSummaryConfirmed>,
+) {
+    debug!("updating post raid screen");
+    for (interaction, mut color, target_state) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                debug!("button pressed");
+                *color = PRESSED_BUTTON.into();
+                confirmed.send(RaidSummaryConfirmed);
+                next_state.set(target_state.0.clone());
+            }
+            Interaction::Hovered => {
+                debug!("button hovered");
+                *color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                debug!("button normal");
+                *color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn bye_post_raid_screen(mut commands: Commands, menu_data: Res<MenuData>) {
+    debug!("bye post raid screen!");
+    commands
+        .entity(menu_data.post_raid_screen_layout)
+        .despawn_recursive();
+}
+
+// helper functions
+
+/// what the summary screen shows
+fn summary_lines(summary: &RaidSummary) -> Vec<String> {
+    let outcome = match summary.outcome {
+        RaidOutcome::InProgress => "IN PROGRESS",
+        RaidOutcome::Exfilled => "EXFILLED",
+        RaidOutcome::Killed => "KILLED IN ACTION",
+        RaidOutcome::MissingInAction => "MISSING IN ACTION",
+    };
+    let mut lines = vec![
+        outcome.to_string(),
+        format!("AI KILLS: {}", summary.ai_kills),
+        format!("OPERATOR KILLS: {}", summary.operator_kills),
+        format!("ASSISTS: {}", summary.assists),
+        format!("CONTRACTS: {}", summary.contract_counter),
+        format!("CONTAINERS OPENED: {}", summary.loot_container_counter),
+        format!("POIS VISITED: {}", summary.pois_visited),
+    ];
+    for item in summary.extracted_items.iter() {
+        lines.push(format!("{} ${}", item.name, item.value));
+    }
+    lines.push(format!("CASH: ${}", summary.extracted_cash));
+    lines
+}
+
+fn spawn_nested_text_bundle(builder: &mut ChildBuilder, font_size: f32, text: &str) {
+    builder
+        .spawn(Text::new(text))
+        .insert(TextFont {
+            font_size,
+            ..default()
+        })
+        .insert(TextColor(Color::srgb(0.9, 0.9, 0.9)));
+}
+
+fn spawn_button_bundle(
+    builder: &mut ChildBuilder,
+    button_name_component: Name,
+    button_text: &str,
+    button_target_state: ButtonTargetState,
+) {
+    builder
+        .spawn(Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .insert(button_name_component.clone())
+        .with_children(|parent| {
+            parent
+                .spawn(Button)
+                .insert(Node {
+                    width: Val::Px(150.),
+                    height: Val::Px(110.),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .insert(BackgroundColor(NORMAL_BUTTON))
+                .insert(button_name_component)
+                .with_children(|parent| {
+                    parent
+                        .spawn(Text::new(button_text))
+                        .insert(TextFont {
+                            font_size: 40.0,
+                            ..default()
+                        })
+                        .insert(TextColor(Color::srgb(0.9, 0.9, 0.9)));
+                })
+                .insert(button_target_state);
+        });
+}
+// --- Post Raid Screen STOP
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::raid_summary::ExtractedItem;
+
+    #[test]
+    fn should_list_raid_summary() {
+        // given
+        let summary = RaidSummary {
+            outcome: RaidOutcome::Exfilled,
+            ai_kills: 3,
+            extracted_items: vec![ExtractedItem {
+                name: String::from("Gold Bar"),
+                value: 500,
+            }],
+            extracted_cash: 1_500,
+            ..default()
+        };
+
+        // when
+        let lines = summary_lines(&summary);
+
+        // then
+        assert_eq!("EXFILLED", lines[0]);
+        assert_eq!("AI KILLS: 3", lines[1]);
+        assert!(lines.contains(&String::from("Gold Bar $500")));
+        assert_eq!(Some(&String::from("CASH: $1500")), lines.last());
+    }
+}
@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use bevy::app::Plugin;
 
+use crate::damage::{DamageOrigin, DamageWeapon};
 use crate::AppState;
 use bevy::prelude::*;
 use bevy_inspector_egui::{inspector_options::ReflectInspectorOptions, InspectorOptions};
@@ -121,19 +122,24 @@ pub struct SingleShot {
 }
 
 // Systems
+
+/// spawns a projectile for each emitter of the shooter. the projectile carries the origin chain
+/// (shooter and weapon) so any damage dealt by it can be attributed later on.
 fn emit_single_shot(
     mut commands: Commands,
     mut single_shot_triggered: EventReader<SingleShot>,
-    projectile_emitters: Query<(&Parent, &ProjectileEmitter, &GlobalTransform)>,
+    projectile_emitters: Query<(Entity, &Parent, &ProjectileEmitter, &GlobalTransform)>,
 ) {
     // TODO: prevent fire rate that is not possible
     for event in single_shot_triggered.read() {
-        for (shooter, pewpew, g_transform) in projectile_emitters.iter() {
+        for (weapon, shooter, pewpew, g_transform) in projectile_emitters.iter() {
             if shooter.get().eq(&event.shooter) {
                 commands
                     .spawn(Projectile::default())
                     .insert(Name::new("Bullet"))
                     .insert(ProjectileOrigin(**shooter))
+                    .insert(DamageOrigin(**shooter))
+                    .insert(DamageWeapon(weapon))
                     .insert(ProjectileTime::default())
                     .insert(Transform::from(*g_transform))
                     .insert(ProjectileVelocity {
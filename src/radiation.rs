@@ -0,0 +1,395 @@
+use bevy::app::Plugin;
+
+use crate::damage::HealthDamageReceived;
+use crate::exfil::Operator;
+use crate::first_person_controller::PlayerControlled;
+use crate::health::Health;
+use crate::inventory::Inventory;
+use crate::loot::{Loot, LootType};
+use crate::raid::Volume;
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "radiation";
+const RADIATION_TICK_SECS: f32 = 1.0;
+/// every this many seconds of exposure the damage of a zone goes up by its intensity
+const RADIATION_ESCALATION_SECS: f32 = 5.0;
+const RADIATION_IMMUNITY_SECS: f32 = 30.0;
+/// shielding never blocks radiation completely
+const MAX_SHIELDING: f32 = 0.9;
+
+// Plugin
+/// radiation zones and carried sources that hurt operators over time, radiation meds and suits to
+/// counter them
+pub struct RadiationPlugin;
+
+impl Plugin for RadiationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ConsumeRadiationMeds>()
+            .add_event::<RadiationMedsConsumed>()
+            .add_systems(OnEnter(Raid), start_radiation)
+            .add_systems(
+                Update,
+                (
+                    player_consumes_meds,
+                    consume_radiation_meds,
+                    update_immunity,
+                    radiation_exposure,
+                    update_geiger,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_radiation);
+    }
+}
+
+// Components
+
+/// radiation zone, the extent is given by a `Volume` relative to the zone position
+#[derive(Component, Debug)]
+pub struct RadiationZone {
+    pub intensity: i32,
+}
+
+/// loot that irradiates the operator carrying it in the backpack, as if the operator was in a
+/// radiation zone of the same intensity
+#[derive(Component, Debug)]
+pub struct RadiationSource {
+    pub intensity: i32,
+}
+
+/// time an operator spent in the current radiation and its intensity
+#[derive(Component, Debug, Default)]
+pub struct RadiationExposure {
+    pub secs: f32,
+    pub intensity: i32,
+}
+
+/// granted by radiation meds, no radiation damage until the timer is done
+#[derive(Component, Debug)]
+pub struct RadiationImmunity(pub Timer);
+
+/// equipment like a radiation suit, fraction of radiation that is blocked
+#[derive(Component, Debug)]
+pub struct RadiationShielding(pub f32);
+
+#[derive(Component)]
+struct GeigerUI;
+
+// Resources
+#[derive(Resource)]
+struct RadiationTick(Timer);
+
+// Events
+
+/// command to consume radiation meds from the backpack
+#[derive(Event, Debug, PartialEq)]
+pub struct ConsumeRadiationMeds {
+    pub operator: Entity,
+}
+
+#[derive(Event, Debug, PartialEq)]
+pub struct RadiationMedsConsumed {
+    pub operator: Entity,
+}
+
+// Systems
+fn start_radiation(mut commands: Commands) {
+    debug!("starting {}", NAME);
+    commands.insert_resource(RadiationTick(Timer::from_seconds(
+        RADIATION_TICK_SECS,
+        TimerMode::Repeating,
+    )));
+    commands
+        .spawn(Text::new(String::new()))
+        .insert(TextFont {
+            font_size: 14.0,
+            ..default()
+        })
+        .insert(TextColor(Color::srgb(0.4, 0.9, 0.2)))
+        .insert(Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(30.0),
+            left: Val::Px(10.0),
+            ..default()
+        })
+        .insert(Name::new("Geiger Counter"))
+        .insert(GeigerUI);
+}
+
+fn player_consumes_meds(
+    key_input: Res<ButtonInput<KeyCode>>,
+    player: Query<Entity, (With<PlayerControlled>, With<Operator>)>,
+    mut consume: EventWriter<ConsumeRadiationMeds>,
+) {
+    if key_input.just_released(KeyCode::KeyR) {
+        if let Ok(operator) = player.get_single() {
+            consume.send(ConsumeRadiationMeds { operator });
+        }
+    }
+}
+
+/// uses up one radiation meds item of the backpack for a timed immunity
+fn consume_radiation_meds(
+    mut commands: Commands,
+    mut consume: EventReader<ConsumeRadiationMeds>,
+    inventories: Query<&Parent, With<Inventory>>,
+    loot: Query<(Entity, &Parent, &LootType), With<Loot>>,
+    mut consumed: EventWriter<RadiationMedsConsumed>,
+) {
+    for event in consume.read() {
+        let meds = loot.iter().find(|(_, backpack, loot_type)| {
+            **loot_type == LootType::RadiationProtection
+                && owned_by(&inventories, backpack, event.operator)
+        });
+        if let Some((meds, _, _)) = meds {
+            debug!("operator {} consumed radiation meds", event.operator);
+            commands.entity(meds).despawn_recursive();
+            commands
+                .entity(event.operator)
+                .insert(RadiationImmunity(Timer::from_seconds(
+                    RADIATION_IMMUNITY_SECS,
+                    TimerMode::Once,
+                )))
+                .remove::<RadiationExposure>();
+            consumed.send(RadiationMedsConsumed {
+                operator: event.operator,
+            });
+        } else {
+            debug!("operator {} has no radiation meds", event.operator);
+        }
+    }
+}
+
+fn update_immunity(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut immunities: Query<(Entity, &mut RadiationImmunity)>,
+) {
+    for (entity, mut immunity) in immunities.iter_mut() {
+        if immunity.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<RadiationImmunity>();
+        }
+    }
+}
+
+/// escalating damage for operators inside of radiation zones or carrying a radiation source
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn radiation_exposure(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut tick: ResMut<RadiationTick>,
+    zones: Query<(Entity, &GlobalTransform, &Volume, &RadiationZone)>,
+    mut operators: Query<
+        (Entity, &GlobalTransform, Option<&mut RadiationExposure>),
+        (With<Operator>, With<Health>, Without<RadiationImmunity>),
+    >,
+    inventories: Query<&Parent, With<Inventory>>,
+    shieldings: Query<(&Parent, &RadiationShielding), With<Loot>>,
+    sources: Query<(Entity, &Parent, &RadiationSource), With<Loot>>,
+    mut health_damage: EventWriter<HealthDamageReceived>,
+) {
+    if !tick.0.tick(time.delta()).just_finished() {
+        return;
+    }
+    for (operator, transform, exposure) in operators.iter_mut() {
+        // the strongest zone the operator is in or source the operator carries deals the damage
+        let carried = sources
+            .iter()
+            .filter(|(_, backpack, _)| owned_by(&inventories, backpack, operator))
+            .map(|(entity, _, source)| (entity, source.intensity));
+        let strongest = zones
+            .iter()
+            .filter(|(_, zone, volume, _)| {
+                volume.contains(zone.translation(), transform.translation())
+            })
+            .map(|(entity, _, _, zone)| (entity, zone.intensity))
+            .chain(carried)
+            .max_by_key(|(_, intensity)| *intensity);
+        let Some((dealer, intensity)) = strongest else {
+            if exposure.is_some() {
+                debug!("operator {} left the radiation", operator);
+                commands.entity(operator).remove::<RadiationExposure>();
+            }
+            continue;
+        };
+        let shielding = shieldings
+            .iter()
+            .filter(|(backpack, _)| owned_by(&inventories, backpack, operator))
+            .map(|(_, shielding)| shielding.0)
+            .sum::<f32>()
+            .clamp(0.0, MAX_SHIELDING);
+        let secs = match exposure {
+            Some(mut exposure) => {
+                exposure.secs += RADIATION_TICK_SECS;
+                exposure.intensity = intensity;
+                exposure.secs
+            }
+            None => {
+                commands.entity(operator).insert(RadiationExposure {
+                    secs: RADIATION_TICK_SECS,
+                    intensity,
+                });
+                RADIATION_TICK_SECS
+            }
+        };
+        let damage = radiation_damage(intensity, secs, shielding);
+        debug!("operator {} takes {} radiation damage", operator, damage);
+        health_damage.send(HealthDamageReceived {
+            entity: operator,
+            damage,
+            dealer: Some(dealer),
+            weapon: None,
+        });
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_geiger(
+    player: Query<
+        (Option<&RadiationExposure>, Option<&RadiationImmunity>),
+        (With<PlayerControlled>, With<Operator>),
+    >,
+    geiger: Query<Entity, With<GeigerUI>>,
+    mut writer: TextUiWriter,
+) {
+    let (Ok((exposure, immunity)), Ok(geiger)) = (player.get_single(), geiger.get_single()) else {
+        return;
+    };
+    let text = match (exposure, immunity) {
+        (_, Some(immunity)) => format!("RAD IMMUNE {:.0}s", immunity.0.remaining_secs().ceil()),
+        (Some(exposure), None) => format!("RAD {}", geiger_bars(exposure.intensity)),
+        (None, None) => String::new(),
+    };
+    *writer.text(geiger, 0) = text;
+}
+
+fn bye_radiation(mut commands: Commands, geiger: Query<Entity, With<GeigerUI>>) {
+    debug!("stopping {}", NAME);
+    commands.remove_resource::<RadiationTick>();
+    for entity in geiger.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// helper functions
+
+fn owned_by(
+    inventories: &Query<&Parent, With<Inventory>>,
+    backpack: &Parent,
+    operator: Entity,
+) -> bool {
+    inventories
+        .get(backpack.get())
+        .is_ok_and(|owner| owner.get() == operator)
+}
+
+fn radiation_damage(intensity: i32, exposure_secs: f32, shielding: f32) -> i32 {
+    let escalation = 1 + (exposure_secs / RADIATION_ESCALATION_SECS) as i32;
+    ((intensity * escalation) as f32 * (1.0 - shielding)).ceil() as i32
+}
+
+fn geiger_bars(intensity: i32) -> String {
+    "|".repeat(intensity.clamp(0, 10) as usize)
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn should_escalate_radiation_damage() {
+        assert_eq!(2, radiation_damage(2, 1.0, 0.0));
+        assert_eq!(4, radiation_damage(2, RADIATION_ESCALATION_SECS, 0.0));
+        assert_eq!(6, radiation_damage(2, 2.0 * RADIATION_ESCALATION_SECS, 0.0));
+        assert_eq!(1, radiation_damage(2, 1.0, 0.5));
+    }
+
+    #[test]
+    fn should_irradiate_carrier_of_radiation_source() {
+        // given
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.insert_resource(RadiationTick(Timer::from_seconds(
+            RADIATION_TICK_SECS,
+            TimerMode::Repeating,
+        )));
+        app.add_event::<HealthDamageReceived>();
+        app.add_systems(Update, radiation_exposure);
+        let operator = app
+            .world_mut()
+            .spawn((Operator, Health::default(), GlobalTransform::default()))
+            .id();
+        let backpack = app.world_mut().spawn(Inventory).set_parent(operator).id();
+        let source = app
+            .world_mut()
+            .spawn((Loot, RadiationSource { intensity: 4 }))
+            .set_parent(backpack)
+            .id();
+        app.world_mut()
+            .spawn((Loot, RadiationShielding(0.5)))
+            .set_parent(backpack);
+
+        // when
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(RADIATION_TICK_SECS));
+        app.update();
+
+        // then
+        assert_eq!(
+            4,
+            app.world()
+                .get::<RadiationExposure>(operator)
+                .unwrap()
+                .intensity
+        );
+        let damage_events = app.world().resource::<Events<HealthDamageReceived>>();
+        let mut damage_reader = damage_events.get_cursor();
+        assert_eq!(
+            vec![&HealthDamageReceived {
+                entity: operator,
+                damage: 2,
+                dealer: Some(source),
+                weapon: None,
+            }],
+            damage_reader.read(damage_events).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_consume_meds_for_immunity() {
+        // given
+        let mut app = App::new();
+        app.add_event::<ConsumeRadiationMeds>();
+        app.add_event::<RadiationMedsConsumed>();
+        app.add_systems(Update, consume_radiation_meds);
+        let operator = app
+            .world_mut()
+            .spawn((Operator, RadiationExposure::default()))
+            .id();
+        let backpack = app.world_mut().spawn(Inventory).set_parent(operator).id();
+        let meds = app
+            .world_mut()
+            .spawn((Loot, LootType::RadiationProtection))
+            .set_parent(backpack)
+            .id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<ConsumeRadiationMeds>>()
+            .send(ConsumeRadiationMeds { operator });
+        app.update();
+
+        // then
+        assert!(app.world().get::<Loot>(meds).is_none());
+        assert!(app.world().get::<RadiationImmunity>(operator).is_some());
+        assert!(app.world().get::<RadiationExposure>(operator).is_none());
+    }
+}
@@ -1,7 +1,10 @@
 // Raid and declare a Raid PluginGroup that is added to the main.rs
 
 use bevy::{
-    math::bounding::Aabb3d,
+    math::{
+        bounding::{Aabb3d, BoundingVolume},
+        Vec3A,
+    },
     prelude::*,
     window::{CursorGrabMode, PrimaryWindow},
 };
@@ -19,8 +22,9 @@ pub enum RaidState {
     Raid,
     AccessLootCache,
     AccessBackpack,
+    AccessKeypad,
     //AccessTacMap,
-    //AccessMissions,
+    AccessMissions,
     //AccessMenu,
 }
 
@@ -36,20 +40,46 @@ pub struct Enemy;
 #[allow(dead_code)]
 pub struct Volume(pub Aabb3d);
 
+impl Volume {
+    /// whether the point is inside of the volume placed at center
+    pub fn contains(&self, center: Vec3, point: Vec3) -> bool {
+        let world_volume = Aabb3d::new(center + Vec3::from(self.0.center()), self.0.half_size());
+        let point = Vec3A::from(point);
+        point.cmpge(world_volume.min).all() && point.cmple(world_volume.max).all()
+    }
+}
+
+#[derive(Component)]
+struct InfilCountdownUI;
+
 // Events
 
+/// whole seconds left until the infil is complete
 #[derive(Event, Debug)]
 struct InfilCounter(u32);
 
+/// the infil helicopters touched down, operators are released
 #[derive(Event)]
-struct InfilComplete;
+pub struct InfilComplete;
 
 // Resources
 
 // TODO: add simple ui "exfil button" for triggering the exfil procedure
 
+/// counts down in real seconds, independent of the fixed timestep
+#[derive(Resource)]
+pub struct InfilCountdown(Timer);
+
+impl InfilCountdown {
+    /// progress of the infil from 0.0 to 1.0
+    pub fn fraction(&self) -> f32 {
+        self.0.fraction()
+    }
+}
+
+/// present as soon as the infil is complete and the player is allowed to move, shoot and interact
 #[derive(Default, Resource)]
-struct InfilCountdown(u32);
+pub struct PlayerControlsEnabled;
 
 #[derive(Default, Resource)]
 #[allow(dead_code)]
@@ -57,6 +87,8 @@ struct LiftoffCountdown(u32);
 
 // TODO: counters for all other phases of exfil
 
+const INFIL_COUNTDOWN_SECS: f32 = 31.0;
+
 // Plugin
 
 pub struct RaidPlugin;
@@ -71,12 +103,13 @@ impl Plugin for RaidPlugin {
                 Update,
                 (
                     update_raid,
+                    infil_countdown,
                     infil_countdown_listener,
                     infil_countdown_complete_listener,
                 )
+                    .chain()
                     .run_if(in_state(Raid)),
             )
-            .add_systems(FixedUpdate, (infil_countdown).run_if(in_state(Raid)))
             .add_systems(OnExit(Raid), bye_raid);
     }
 }
@@ -98,7 +131,25 @@ fn start_raid(mut commands: Commands, mut q_windows: Query<&mut Window, With<Pri
     //primary_window.cursor.visible = false;
 
     debug!("starting raid called");
-    commands.insert_resource(InfilCountdown(31));
+    commands.insert_resource(InfilCountdown(Timer::from_seconds(
+        INFIL_COUNTDOWN_SECS,
+        TimerMode::Once,
+    )));
+    commands
+        .spawn(Text::new(infil_text(INFIL_COUNTDOWN_SECS as u32)))
+        .insert(TextFont {
+            font_size: 40.0,
+            ..default()
+        })
+        .insert(TextColor(Color::srgb(0.9, 0.9, 0.9)))
+        .insert(Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(30.0),
+            justify_self: JustifySelf::Center,
+            ..default()
+        })
+        .insert(Name::new("Infil Countdown"))
+        .insert(InfilCountdownUI);
     commands.insert_resource(LiftoffCountdown(34));
     // chosen location cleanup
     commands.remove_resource::<ChosenLocation>();
@@ -108,9 +159,10 @@ fn update_raid(mut _next_state: ResMut<NextState<AppState>>) {
     debug!("updating raid called");
 }
 
+#[allow(clippy::type_complexity)]
 fn bye_raid(
     mut commands: Commands,
-    query: Query<Entity, With<FreeLookCamera>>,
+    query: Query<Entity, Or<(With<FreeLookCamera>, With<InfilCountdownUI>)>>,
     mut q_windows: Query<&mut Window, With<PrimaryWindow>>,
 ) {
     let mut primary_window = q_windows.single_mut();
@@ -119,29 +171,46 @@ fn bye_raid(
     primary_window.cursor_options.visible = true;
 
     debug!("exiting raid called");
+    commands.remove_resource::<InfilCountdown>();
+    commands.remove_resource::<PlayerControlsEnabled>();
     for entity in &query {
         commands.entity(entity).despawn_recursive();
     }
 }
 
 fn infil_countdown(
-    mut infil_countdown: ResMut<InfilCountdown>,
+    time: Res<Time>,
+    infil_countdown: Option<ResMut<InfilCountdown>>,
     mut event: EventWriter<InfilCounter>, // new ping we are currently searching for
 ) {
     debug!("infil countdown system called");
-    if infil_countdown.0 > 0 {
-        infil_countdown.0 -= 1;
-        event.send(InfilCounter(infil_countdown.0));
-        debug!("infil countdown event send : {:?}", infil_countdown.0);
+    let Some(mut infil_countdown) = infil_countdown else {
+        return;
+    };
+    if infil_countdown.0.finished() {
+        return;
+    }
+    let before = infil_countdown.0.remaining_secs().ceil() as u32;
+    infil_countdown.0.tick(time.delta());
+    let after = infil_countdown.0.remaining_secs().ceil() as u32;
+    // one event per whole second
+    if before != after {
+        event.send(InfilCounter(after));
+        debug!("infil countdown event send : {:?}", after);
     }
 }
+
 fn infil_countdown_listener(
     mut events: EventReader<InfilCounter>,
     mut complete: EventWriter<InfilComplete>,
+    ui: Query<Entity, With<InfilCountdownUI>>,
+    mut writer: TextUiWriter,
 ) {
     for event in events.read() {
-        // TODO: print countdown on screen, output just to log for now
         debug!("counter received: {:?}", event);
+        if let Ok(ui) = ui.get_single() {
+            *writer.text(ui, 0) = infil_text(event.0);
+        }
         if event.0 == 0 {
             complete.send(InfilComplete);
             debug!("sending complete event");
@@ -149,8 +218,40 @@ fn infil_countdown_listener(
     }
 }
 
-fn infil_countdown_complete_listener(mut events: EventReader<InfilComplete>) {
+fn infil_countdown_complete_listener(
+    mut commands: Commands,
+    mut events: EventReader<InfilComplete>,
+    ui: Query<Entity, With<InfilCountdownUI>>,
+) {
     for _ in events.read() {
-        debug!("'activate' controls so player can move now. match is in progress now")
+        debug!("activate controls so player can move now. match is in progress now");
+        commands.init_resource::<PlayerControlsEnabled>();
+        for entity in ui.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+// helper functions
+
+fn infil_text(secs: u32) -> String {
+    format!("INFIL {}", secs)
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn should_detect_points_inside_volume() {
+        // given
+        let volume = Volume(Aabb3d::new(Vec3::ZERO, Vec3::new(4.0, 2.0, 4.0)));
+        let center = Vec3::new(10.0, 0.0, 0.0);
+
+        // then
+        assert!(volume.contains(center, Vec3::new(12.0, 1.0, -3.0)));
+        assert!(!volume.contains(center, Vec3::new(2.0, 1.0, 0.0)));
     }
 }
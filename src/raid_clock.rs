@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use bevy::app::Plugin;
+
+use crate::exfil_timers::{GasSpreadingCountdown, RaidCountdown};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "raid clock";
+
+// Plugin
+/// on screen clock for the time until the gas and the progress of the gas
+pub struct RaidClockPlugin;
+
+impl Plugin for RaidClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_raid_clock)
+            .add_systems(Update, (update_raid_clock).run_if(in_state(AppState::Raid)))
+            .add_systems(OnExit(AppState::Raid), bye_raid_clock);
+    }
+}
+
+// Components
+
+#[derive(Component)]
+struct RaidClockUI;
+
+// Resources
+
+// Events
+
+// Systems
+fn start_raid_clock(mut commands: Commands) {
+    debug!("starting {}", NAME);
+    commands
+        .spawn(Text::new(String::new()))
+        .insert(TextFont {
+            font_size: 14.0,
+            ..default()
+        })
+        .insert(TextColor(Color::srgb(0.9, 0.9, 0.9)))
+        .insert(Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        })
+        .insert(Name::new("Raid Clock"))
+        .insert(RaidClockUI);
+}
+
+fn update_raid_clock(
+    raid_countdown: Option<Res<RaidCountdown>>,
+    gas_spreading: Option<Res<GasSpreadingCountdown>>,
+    clock: Query<Entity, With<RaidClockUI>>,
+    mut writer: TextUiWriter,
+) {
+    let Ok(clock) = clock.get_single() else {
+        return;
+    };
+    let text = if let Some(countdown) = raid_countdown {
+        format!("GAS IN {}", clock_text(countdown.remaining()))
+    } else if let Some(gas) = gas_spreading {
+        format!("GAS {:.0}%", gas.fraction() * 100.0)
+    } else {
+        String::new()
+    };
+    *writer.text(clock, 0) = text;
+}
+
+fn bye_raid_clock(mut commands: Commands, clock: Query<Entity, With<RaidClockUI>>) {
+    debug!("stopping {}", NAME);
+    for entity in clock.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// helper functions
+
+/// minutes and seconds, rounded up so the clock reads 00:00 only when the time is up
+fn clock_text(remaining: Duration) -> String {
+    let secs = remaining.as_secs_f32().ceil() as u64;
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn should_format_clock() {
+        assert_eq!("00:00", clock_text(Duration::ZERO));
+        assert_eq!("00:01", clock_text(Duration::from_millis(200)));
+        assert_eq!("01:30", clock_text(Duration::from_secs(90)));
+        assert_eq!("25:00", clock_text(Duration::from_secs(25 * 60)));
+    }
+}
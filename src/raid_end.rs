@@ -0,0 +1,177 @@
+use bevy::app::Plugin;
+
+use crate::exfil::Operator;
+use crate::exfil_timers::GasSpreadingFinished;
+use crate::raid_summary::{RaidOutcome, RaidSummaries};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "raid end";
+
+// Plugin
+/// resolves the raid once the gas has fully spread or the player is out
+pub struct RaidEndPlugin;
+
+impl Plugin for RaidEndPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MissingInAction>()
+            .add_systems(OnEnter(Raid), start_raid_end)
+            .add_systems(
+                Update,
+                (gas_spreading_finished, player_raid_over).run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_raid_end);
+    }
+}
+
+// Components
+
+// Resources
+
+// Events
+
+/// operator did not make it out of the AO before the gas fully spread
+#[derive(Event, Debug, PartialEq)]
+pub struct MissingInAction {
+    pub operator: Entity,
+}
+
+// Systems
+fn start_raid_end(mut _commands: Commands) {
+    debug!("starting {}", NAME);
+}
+
+/// operators without an outcome are missing in action, afterwards the raid is over
+fn gas_spreading_finished(
+    mut finished: EventReader<GasSpreadingFinished>,
+    operators: Query<Entity, With<Operator>>,
+    mut summaries: ResMut<RaidSummaries>,
+    mut missing_in_action: EventWriter<MissingInAction>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if finished.read().count() == 0 {
+        return;
+    }
+    for operator in operators.iter() {
+        let summary = summaries.map.entry(operator).or_default();
+        if summary.outcome == RaidOutcome::InProgress {
+            debug!("operator {} is missing in action", operator);
+            summary.outcome = RaidOutcome::MissingInAction;
+            missing_in_action.send(MissingInAction { operator });
+        }
+    }
+    next_state.set(AppState::PostRaid);
+}
+
+/// the player exfilled or got killed, on to the summary
+fn player_raid_over(summaries: Res<RaidSummaries>, mut next_state: ResMut<NextState<AppState>>) {
+    let outcome = summaries
+        .player
+        .and_then(|player| summaries.map.get(&player))
+        .map(|summary| summary.outcome);
+    if matches!(
+        outcome,
+        Some(RaidOutcome::Exfilled) | Some(RaidOutcome::Killed)
+    ) {
+        debug!("player raid is over: {:?}", outcome);
+        next_state.set(AppState::PostRaid);
+    }
+}
+
+fn bye_raid_end(mut _commands: Commands) {
+    debug!("stopping {}", NAME);
+}
+
+// helper functions
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::raid_summary::RaidSummary;
+    use bevy::state::app::StatesPlugin;
+
+    #[test]
+    fn should_mark_remaining_operators_missing_in_action() {
+        // given
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+        app.init_state::<AppState>();
+        app.add_event::<GasSpreadingFinished>();
+        app.add_event::<MissingInAction>();
+        app.init_resource::<RaidSummaries>();
+        app.add_systems(Update, gas_spreading_finished);
+        let exfilled = app.world_mut().spawn(Operator).id();
+        let remaining = app.world_mut().spawn(Operator).id();
+        app.world_mut().resource_mut::<RaidSummaries>().map.insert(
+            exfilled,
+            RaidSummary {
+                outcome: RaidOutcome::Exfilled,
+                ..default()
+            },
+        );
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<GasSpreadingFinished>>()
+            .send(GasSpreadingFinished);
+        app.update();
+
+        // then
+        let summaries = app.world().resource::<RaidSummaries>();
+        assert_eq!(RaidOutcome::Exfilled, summaries.map[&exfilled].outcome);
+        assert_eq!(
+            RaidOutcome::MissingInAction,
+            summaries.map[&remaining].outcome
+        );
+        let mia_events = app.world().resource::<Events<MissingInAction>>();
+        let mut mia_reader = mia_events.get_cursor();
+        assert_eq!(
+            vec![&MissingInAction {
+                operator: remaining
+            }],
+            mia_reader.read(mia_events).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(&AppState::PostRaid),
+            match app.world().resource::<NextState<AppState>>() {
+                NextState::Pending(state) => Some(state),
+                NextState::Unchanged => None,
+            }
+        );
+    }
+
+    #[test]
+    fn should_end_raid_when_player_exfilled() {
+        // given
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+        app.init_state::<AppState>();
+        app.add_systems(Update, player_raid_over);
+        let player = app.world_mut().spawn(Operator).id();
+        let mut summaries = RaidSummaries {
+            player: Some(player),
+            ..default()
+        };
+        summaries.map.insert(
+            player,
+            RaidSummary {
+                outcome: RaidOutcome::Exfilled,
+                ..default()
+            },
+        );
+        app.insert_resource(summaries);
+
+        // when
+        app.update();
+
+        // then
+        assert!(matches!(
+            app.world().resource::<NextState<AppState>>(),
+            NextState::Pending(AppState::PostRaid)
+        ));
+    }
+}
@@ -0,0 +1,505 @@
+use std::env;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use bevy::app::Plugin;
+use serde::Serialize;
+
+use crate::choose_location::ChosenLocation;
+use crate::contracts::{ContractAccepted, ContractCancelled, ContractFailed, FinishedContract};
+use crate::damage::{ArmorDamageReceived, HealthDamageReceived};
+use crate::death::EntityDied;
+use crate::exfil::{ExfilCalled, ExfilExitedAO};
+use crate::interaction::Interact;
+use crate::inventory::{DropLoot, StowLoot, StowedLoot};
+use crate::out_of_bounds::{OperaterInOfBounds, OperaterOutOfBounds, OperaterOutOfBoundsExpired};
+use crate::wallet::StowedMoney;
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "raid event log";
+/// path of the event log file, the raid event log is off when it is not set
+const RAID_EVENT_LOG_ENV: &str = "RAID_EVENT_LOG";
+
+// Plugin
+/// opt-in recorder of the gameplay events of a raid, one JSON line per event
+pub struct RaidEventLogPlugin;
+
+impl Plugin for RaidEventLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RaidEventLog>()
+            .add_systems(OnEnter(Raid), start_raid_event_log)
+            .add_systems(
+                Update,
+                (
+                    log_events::<Interact>,
+                    log_events::<StowLoot>,
+                    log_events::<StowedLoot>,
+                    log_events::<DropLoot>,
+                    log_events::<StowedMoney>,
+                    log_events::<ContractAccepted>,
+                    log_events::<FinishedContract>,
+                    log_events::<ContractFailed>,
+                    log_events::<ContractCancelled>,
+                    log_events::<ArmorDamageReceived>,
+                    log_events::<HealthDamageReceived>,
+                    log_events::<EntityDied>,
+                    log_events::<ExfilCalled>,
+                    log_events::<ExfilExitedAO>,
+                    log_events::<OperaterOutOfBounds>,
+                    log_events::<OperaterInOfBounds>,
+                    log_events::<OperaterOutOfBoundsExpired>,
+                    flush_raid_event_log,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Raid).and(resource_exists::<RaidEventRecorder>)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_raid_event_log);
+    }
+}
+
+// Components
+
+// Resources
+
+/// where the raid events are written to, insert with a path to opt in, by default the path is
+/// taken from the `RAID_EVENT_LOG` environment variable
+#[derive(Resource, Debug)]
+pub struct RaidEventLog {
+    pub path: Option<PathBuf>,
+}
+
+impl Default for RaidEventLog {
+    fn default() -> Self {
+        RaidEventLog {
+            path: env::var_os(RAID_EVENT_LOG_ENV).map(PathBuf::from),
+        }
+    }
+}
+
+/// open event log of the running raid
+#[derive(Resource)]
+struct RaidEventRecorder {
+    writer: BufWriter<File>,
+    /// elapsed time at the start of the raid, events are logged relative to it
+    started: Duration,
+}
+
+impl RaidEventRecorder {
+    fn record(&mut self, elapsed: Duration, event: RaidEvent) {
+        let line = LogLine {
+            secs: elapsed.saturating_sub(self.started).as_secs_f32(),
+            event,
+        };
+        if let Err(error) = serde_json::to_writer(&mut self.writer, &line)
+            .map_err(io::Error::from)
+            .and_then(|_| writeln!(self.writer))
+        {
+            warn!("{}: could not write event: {}", NAME, error);
+        }
+    }
+}
+
+/// one line of the event log, entities are logged with their bits which stay the same for the
+/// lifetime of the entity
+#[derive(Serialize, Debug)]
+struct LogLine {
+    /// since the start of the raid
+    secs: f32,
+    #[serde(flatten)]
+    event: RaidEvent,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(tag = "event")]
+enum RaidEvent {
+    RaidStarted {
+        location: Option<String>,
+    },
+    RaidEnded,
+    Interact {
+        interaction: u64,
+        operator: u64,
+    },
+    StowLoot {
+        operator: u64,
+        loot: u64,
+        loot_type: String,
+    },
+    StowedLoot {
+        operator: u64,
+        loot: u64,
+    },
+    DropLoot {
+        operator: u64,
+        loot: u64,
+    },
+    StowedMoney {
+        operator: u64,
+        amount: u32,
+    },
+    ContractAccepted {
+        contract: u32,
+    },
+    FinishedContract {
+        contract: u32,
+    },
+    ContractFailed {
+        contract: u32,
+    },
+    ContractCancelled {
+        contract: u32,
+    },
+    ArmorDamageReceived {
+        entity: u64,
+        damage: i32,
+        dealer: Option<u64>,
+        weapon: Option<u64>,
+    },
+    HealthDamageReceived {
+        entity: u64,
+        damage: i32,
+        dealer: Option<u64>,
+        weapon: Option<u64>,
+    },
+    EntityDied {
+        death: u64,
+        killer: Option<u64>,
+        killer_squad: Option<u32>,
+        death_squad: Option<u32>,
+        death_kind: String,
+    },
+    ExfilCalled {
+        exfil: u64,
+        operator: u64,
+    },
+    ExfilExitedAO {
+        operator: u64,
+    },
+    OutOfBounds {
+        operator: u64,
+    },
+    InOfBounds {
+        operator: u64,
+    },
+    OutOfBoundsExpired {
+        operator: u64,
+    },
+}
+
+/// gameplay event that ends up in the event log
+trait Logged: Event {
+    fn raid_event(&self) -> RaidEvent;
+}
+
+impl Logged for Interact {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::Interact {
+            interaction: self.interaction_entity.to_bits(),
+            operator: self.operator_entity.to_bits(),
+        }
+    }
+}
+
+impl Logged for StowLoot {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::StowLoot {
+            operator: self.stowing_entity.to_bits(),
+            loot: self.loot.to_bits(),
+            loot_type: format!("{:?}", self.loot_type),
+        }
+    }
+}
+
+impl Logged for StowedLoot {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::StowedLoot {
+            operator: self.stowing_entity.to_bits(),
+            loot: self.loot.to_bits(),
+        }
+    }
+}
+
+impl Logged for DropLoot {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::DropLoot {
+            operator: self.dropping_entity.to_bits(),
+            loot: self.loot.to_bits(),
+        }
+    }
+}
+
+impl Logged for StowedMoney {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::StowedMoney {
+            operator: self.stowing_entity.to_bits(),
+            amount: self.amount,
+        }
+    }
+}
+
+impl Logged for ContractAccepted {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::ContractAccepted {
+            contract: self.contract_id.0,
+        }
+    }
+}
+
+impl Logged for FinishedContract {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::FinishedContract {
+            contract: self.contract_id.0,
+        }
+    }
+}
+
+impl Logged for ContractFailed {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::ContractFailed {
+            contract: self.contract_id.0,
+        }
+    }
+}
+
+impl Logged for ContractCancelled {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::ContractCancelled {
+            contract: self.contract_id.0,
+        }
+    }
+}
+
+impl Logged for ArmorDamageReceived {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::ArmorDamageReceived {
+            entity: self.entity.to_bits(),
+            damage: self.damage,
+            dealer: self.dealer.map(Entity::to_bits),
+            weapon: self.weapon.map(Entity::to_bits),
+        }
+    }
+}
+
+impl Logged for HealthDamageReceived {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::HealthDamageReceived {
+            entity: self.entity.to_bits(),
+            damage: self.damage,
+            dealer: self.dealer.map(Entity::to_bits),
+            weapon: self.weapon.map(Entity::to_bits),
+        }
+    }
+}
+
+impl Logged for EntityDied {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::EntityDied {
+            death: self.death.to_bits(),
+            killer: self.killer.map(Entity::to_bits),
+            killer_squad: self.killer_squad.as_ref().map(|squad| squad.0),
+            death_squad: self.death_squad.as_ref().map(|squad| squad.0),
+            death_kind: format!("{:?}", self.death_kind),
+        }
+    }
+}
+
+impl Logged for ExfilCalled {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::ExfilCalled {
+            exfil: self.exfil_entity.to_bits(),
+            operator: self.calling_entity.to_bits(),
+        }
+    }
+}
+
+impl Logged for ExfilExitedAO {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::ExfilExitedAO {
+            operator: self.operator_entity.to_bits(),
+        }
+    }
+}
+
+impl Logged for OperaterOutOfBounds {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::OutOfBounds {
+            operator: self.operator_entity.to_bits(),
+        }
+    }
+}
+
+impl Logged for OperaterInOfBounds {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::InOfBounds {
+            operator: self.operator_entity.to_bits(),
+        }
+    }
+}
+
+impl Logged for OperaterOutOfBoundsExpired {
+    fn raid_event(&self) -> RaidEvent {
+        RaidEvent::OutOfBoundsExpired {
+            operator: self.operator_entity.to_bits(),
+        }
+    }
+}
+
+// Events
+
+// Systems
+
+/// every raid is appended to the log, starting with the location of the raid
+fn start_raid_event_log(
+    mut commands: Commands,
+    time: Res<Time>,
+    event_log: Res<RaidEventLog>,
+    location: Option<Res<ChosenLocation>>,
+) {
+    debug!("starting {}", NAME);
+    let Some(path) = event_log.path.as_ref() else {
+        return;
+    };
+    let file = match OpenOptions::new().create(true).append(true).open(path) {
+        Ok(file) => file,
+        Err(error) => {
+            warn!("{}: could not open {:?}: {}", NAME, path, error);
+            return;
+        }
+    };
+    let mut recorder = RaidEventRecorder {
+        writer: BufWriter::new(file),
+        started: time.elapsed(),
+    };
+    recorder.record(
+        time.elapsed(),
+        RaidEvent::RaidStarted {
+            location: location.map(|location| location.0.clone()),
+        },
+    );
+    commands.insert_resource(recorder);
+}
+
+fn log_events<E: Logged>(
+    mut events: EventReader<E>,
+    time: Res<Time>,
+    mut recorder: ResMut<RaidEventRecorder>,
+) {
+    for event in events.read() {
+        recorder.record(time.elapsed(), event.raid_event());
+    }
+}
+
+/// a crash should not lose the events leading up to it
+fn flush_raid_event_log(mut recorder: ResMut<RaidEventRecorder>) {
+    if let Err(error) = recorder.writer.flush() {
+        warn!("{}: could not flush: {}", NAME, error);
+    }
+}
+
+fn bye_raid_event_log(
+    mut commands: Commands,
+    time: Res<Time>,
+    recorder: Option<ResMut<RaidEventRecorder>>,
+) {
+    debug!("stopping {}", NAME);
+    let Some(mut recorder) = recorder else {
+        return;
+    };
+    recorder.record(time.elapsed(), RaidEvent::RaidEnded);
+    if let Err(error) = recorder.writer.flush() {
+        warn!("{}: could not flush: {}", NAME, error);
+    }
+    commands.remove_resource::<RaidEventRecorder>();
+}
+
+// helper functions
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::contracts::ContractId;
+    use std::fs;
+
+    #[test]
+    fn should_log_events_as_json_lines() {
+        // given
+        let path = env::temp_dir().join(format!("raid_event_log_{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.add_event::<StowedMoney>();
+        app.add_event::<FinishedContract>();
+        app.insert_resource(RaidEventLog {
+            path: Some(path.clone()),
+        });
+        app.insert_resource(ChosenLocation(String::from("Farm")));
+        app.add_systems(Startup, start_raid_event_log);
+        app.add_systems(
+            Update,
+            (
+                log_events::<StowedMoney>,
+                log_events::<FinishedContract>,
+                flush_raid_event_log,
+            )
+                .chain(),
+        );
+        app.update();
+        let operator = app.world_mut().spawn_empty().id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(2));
+        app.world_mut()
+            .resource_mut::<Events<StowedMoney>>()
+            .send(StowedMoney {
+                stowing_entity: operator,
+                amount: 250,
+            });
+        app.world_mut()
+            .resource_mut::<Events<FinishedContract>>()
+            .send(FinishedContract {
+                contract_id: ContractId(7),
+            });
+        app.update();
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // then
+        let lines: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(3, lines.len());
+        assert_eq!("RaidStarted", lines[0]["event"]);
+        assert_eq!("Farm", lines[0]["location"]);
+        assert_eq!("StowedMoney", lines[1]["event"]);
+        assert_eq!(operator.to_bits(), lines[1]["operator"]);
+        assert_eq!(250, lines[1]["amount"]);
+        assert_eq!(2.0, lines[1]["secs"]);
+        assert_eq!("FinishedContract", lines[2]["event"]);
+        assert_eq!(7, lines[2]["contract"]);
+    }
+
+    #[test]
+    fn should_not_record_without_path() {
+        // given
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.insert_resource(RaidEventLog { path: None });
+        app.add_systems(Update, start_raid_event_log);
+
+        // when
+        app.update();
+
+        // then
+        assert!(app.world().get_resource::<RaidEventRecorder>().is_none());
+    }
+}
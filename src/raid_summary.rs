@@ -1,13 +1,19 @@
 use bevy::app::Plugin;
 
 use crate::contracts::{Contracts, FinishedContract};
+use crate::death::{DeathKind, EntityDied};
 use crate::exfil::{ExfilExitedAO, Operator};
-use crate::inventory::{Inventory, ItemSlot, WeaponSlot};
+use crate::first_person_controller::PlayerControlled;
+use crate::inventory::{Inventory, ItemSlot, LootCacheOpened, WeaponSlot};
+use crate::loot::{LootName, Price};
+use crate::point_of_interest::PointOfInterestVisited;
 use crate::squad::{SquadId, Squads};
+use crate::wallet::Wallet;
 use crate::AppState;
 use crate::AppState::Raid;
 use bevy::{prelude::*, utils::HashMap};
 use bevy_inspector_egui::prelude::*;
+use serde::{Deserialize, Serialize};
 
 // Constants
 const NAME: &str = "raid_summary";
@@ -26,6 +32,9 @@ impl Plugin for RaidSummaryPlugin {
                     update_raid_summary_system,
                     exit_ao_received,
                     finished_contract_received,
+                    entity_died_received,
+                    loot_cache_opened_received,
+                    poi_visited_received,
                 )
                     .run_if(in_state(AppState::Raid)),
             )
@@ -42,25 +51,49 @@ impl Plugin for RaidSummaryPlugin {
 #[reflect(Resource, InspectorOptions)]
 pub struct RaidSummaries {
     pub map: HashMap<Entity, RaidSummary>,
+    /// the operator of the player, the summary screen shows this one
+    pub player: Option<Entity>,
 }
 
 #[allow(dead_code)]
 #[derive(Default, Reflect, InspectorOptions, Debug, PartialEq)]
 #[reflect(InspectorOptions)]
 pub struct RaidSummary {
-    // TODO: contracts, operator kills, money extracted
-    // can be updated mid game via events and only at the end screen printed out somehow
-    // items:
-    // AI Kills
-    // player revives
-    // Loot Containers Opened
-    // POIs visited
-    // Misc Items extracted (do this one at the end)
-    // Cash Value Extracted (included cooldown calculation, also at the end)
+    // TODO: player revives
     /// contracts done
     pub contract_counter: u32,
     /// loot container opened
     pub loot_container_counter: u32,
+    /// enemy AI killed
+    pub ai_kills: u32,
+    /// enemy operators killed
+    pub operator_kills: u32,
+    /// kills assisted
+    pub assists: u32,
+    /// how the raid ended for the operator
+    pub outcome: RaidOutcome,
+    /// points of interest visited
+    pub pois_visited: u32,
+    /// backpack contents when leaving the AO
+    pub extracted_items: Vec<ExtractedItem>,
+    /// wallet contents when leaving the AO
+    pub extracted_cash: u32,
+}
+
+#[derive(Default, Reflect, Debug, PartialEq, Clone)]
+pub struct ExtractedItem {
+    pub name: String,
+    pub value: u32,
+}
+
+#[derive(Default, Reflect, Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum RaidOutcome {
+    #[default]
+    InProgress,
+    Exfilled,
+    Killed,
+    /// still in the AO when the gas fully spread
+    MissingInAction,
 }
 
 // Events
@@ -72,9 +105,15 @@ fn start_raid_summary_system(mut commands: Commands) {
     commands.insert_resource(RaidSummaries::default());
 }
 
-fn operator_added(added: Query<Entity, Added<Operator>>, mut summaries: ResMut<RaidSummaries>) {
-    for operator in added.iter() {
+fn operator_added(
+    added: Query<(Entity, Has<PlayerControlled>), Added<Operator>>,
+    mut summaries: ResMut<RaidSummaries>,
+) {
+    for (operator, player) in added.iter() {
         summaries.map.insert(operator, RaidSummary::default());
+        if player {
+            summaries.player = Some(operator);
+        }
     }
 }
 
@@ -114,41 +153,87 @@ fn finished_contract_received(
     }
 }
 
-// TODO: query for the actual exfilled operator
+fn entity_died_received(
+    mut entity_died: EventReader<EntityDied>,
+    mut summaries: ResMut<RaidSummaries>,
+) {
+    for event in entity_died.read() {
+        if let Some(summary) = summaries.map.get_mut(&event.death) {
+            summary.outcome = RaidOutcome::Killed;
+        }
+        if let Some(summary) = event.killer.and_then(|k| summaries.map.get_mut(&k)) {
+            match event.death_kind {
+                DeathKind::Enemy => summary.ai_kills += 1,
+                DeathKind::Operator => summary.operator_kills += 1,
+                DeathKind::Other => {}
+            }
+        }
+        for assist in event.assists.iter() {
+            if let Some(summary) = summaries.map.get_mut(assist) {
+                summary.assists += 1;
+            }
+        }
+    }
+}
+
+/// every operator that left the AO on board of an exfil helicopter, of any squad. whatever
+/// is in the backpack and the wallet at that point is extracted.
 #[allow(clippy::type_complexity)]
 fn exit_ao_received(
     mut exited_ao: EventReader<ExfilExitedAO>,
-    operator_query: Query<(Entity, Option<&Name>, &Children), With<Operator>>,
-    backpack_query: Query<(Entity, &Children), With<Inventory>>,
-    items_query: Query<(Entity, Option<&Name>), With<ItemSlot>>,
-    weapons_query: Query<(Entity, Option<&Name>), With<WeaponSlot>>,
+    operator_query: Query<(Option<&Children>, Option<&Wallet>), With<Operator>>,
+    backpack_query: Query<&Children, With<Inventory>>,
+    items_query: Query<
+        (Option<&LootName>, Option<&Name>, Option<&Price>),
+        Or<(With<ItemSlot>, With<WeaponSlot>)>,
+    >,
+    mut summaries: ResMut<RaidSummaries>,
 ) {
     for event in exited_ao.read() {
         debug!(
             "operator '{:?}' exited the AO, let me do a summary:",
             event.operator_entity
         );
+        let summary = summaries.map.entry(event.operator_entity).or_default();
+        summary.outcome = RaidOutcome::Exfilled;
 
-        let event_op = operator_query.get(event.operator_entity).unwrap(); // maybe we dont need operator query at
-                                                                           // all since it given from the event
-        for (_, _, inventories) in operator_query.iter().filter(|(op, _, _)| *op == event_op.0) {
-            // TODO
-            for inventory in inventories {
-                // TODO
-                for (_, contents) in backpack_query.iter().filter(|(inv, _)| inv == inventory) {
-                    // TODO
-                    for content in contents {
-                        let item = items_query.get(*content);
-                        let weapon = weapons_query.get(*content);
-                        if let Ok(i) = item {
-                            debug!("item: {:?} : {:?}", i.0, i.1);
-                        }
-                        if let Ok(w) = weapon {
-                            debug!("weapon: {:?} : {:?}", w.0, w.1);
-                        }
-                    }
-                }
-            }
+        let Ok((children, wallet)) = operator_query.get(event.operator_entity) else {
+            continue;
+        };
+        summary.extracted_cash = wallet.map_or(0, |wallet| wallet.money);
+        summary.extracted_items = children
+            .into_iter()
+            .flatten()
+            .filter_map(|child| backpack_query.get(*child).ok())
+            .flatten()
+            .filter_map(|content| items_query.get(*content).ok())
+            .map(|(loot_name, name, price)| extracted_item(loot_name, name, price))
+            .collect();
+        debug!(
+            "extracted: {:?}, cash: {}",
+            summary.extracted_items, summary.extracted_cash
+        );
+    }
+}
+
+fn loot_cache_opened_received(
+    mut opened: EventReader<LootCacheOpened>,
+    mut summaries: ResMut<RaidSummaries>,
+) {
+    for event in opened.read() {
+        if let Some(summary) = summaries.map.get_mut(&event.operator) {
+            summary.loot_container_counter += 1;
+        }
+    }
+}
+
+fn poi_visited_received(
+    mut visited: EventReader<PointOfInterestVisited>,
+    mut summaries: ResMut<RaidSummaries>,
+) {
+    for event in visited.read() {
+        if let Some(summary) = summaries.map.get_mut(&event.operator) {
+            summary.pois_visited += 1;
         }
     }
 }
@@ -159,28 +244,77 @@ fn bye_raid_summary_system(mut _commands: Commands) {
 
 // helper functions
 
+fn extracted_item(
+    loot_name: Option<&LootName>,
+    name: Option<&Name>,
+    price: Option<&Price>,
+) -> ExtractedItem {
+    let name = match (loot_name, name) {
+        (Some(loot_name), _) => loot_name.0.clone(),
+        (None, Some(name)) => name.to_string(),
+        (None, None) => String::from("Unknown"),
+    };
+    ExtractedItem {
+        name,
+        value: price.map_or(0, |price| price.0),
+    }
+}
+
 // tests
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
-    //use super::*;
+    use super::*;
 
-    /*
     #[test]
-    fn should_test_something() {
+    fn should_extract_backpack_and_wallet() {
         // given
-        //let mut app = App::new();
+        let mut app = App::new();
+        app.add_event::<ExfilExitedAO>();
+        app.init_resource::<RaidSummaries>();
+        app.add_systems(Update, exit_ao_received);
+        let operator = app
+            .world_mut()
+            .spawn((
+                Operator,
+                Wallet {
+                    money: 1_500,
+                    ..default()
+                },
+            ))
+            .id();
+        let backpack = app.world_mut().spawn(Inventory).set_parent(operator).id();
+        app.world_mut()
+            .spawn((
+                ItemSlot(0),
+                LootName(String::from("Gold Bar")),
+                Price(50_000),
+            ))
+            .set_parent(backpack);
+        app.world_mut()
+            .spawn((WeaponSlot(0), Name::new("Rifle")))
+            .set_parent(backpack);
 
         // when
-        //app.add_event::<HealthDamageReceived>();
-        //app.add_systems(Update, damage_received_listener);
-        //let entity = app.borrow_mut().world.spawn(Health(100)).id();
-        //app.borrow_mut().world.resource_mut::<Events<HealthDamageReceived>>().send(HealthDamageReceived { entity, damage: 10 });
-        //app.update();
+        app.world_mut()
+            .resource_mut::<Events<ExfilExitedAO>>()
+            .send(ExfilExitedAO {
+                operator_entity: operator,
+            });
+        app.update();
 
         // then
-        //assert!(app.world.get::<Health>(entity).is_some());
-        //assert_eq!(app.world.get::<Health>(entity).unwrap().0, 90);
+        let summary = &app.world().resource::<RaidSummaries>().map[&operator];
+        assert_eq!(RaidOutcome::Exfilled, summary.outcome);
+        assert_eq!(1_500, summary.extracted_cash);
+        assert_eq!(2, summary.extracted_items.len());
+        assert!(summary.extracted_items.contains(&ExtractedItem {
+            name: String::from("Gold Bar"),
+            value: 50_000,
+        }));
+        assert!(summary.extracted_items.contains(&ExtractedItem {
+            name: String::from("Rifle"),
+            value: 0,
+        }));
     }
-    */
 }
@@ -0,0 +1,298 @@
+use bevy::app::Plugin;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::contracts::{
+    contract_squad, ContractAccepted, ContractCompleted, ContractGeneralArea, ContractId,
+    ContractState, ContractStateMachine, ContractType, Contracts, RaidWeaponStashState,
+};
+use crate::interaction::Interactable;
+use crate::inventory::{Inventory, ItemSlots, StowLoot, WeaponSlot, WeaponSlots};
+use crate::keypad::Keypad;
+use crate::lock::{Lock, Unlocked};
+use crate::loot::{ItemType, Loot, LootCacheState, LootName, LootType, Price, Rarity};
+use crate::squad::{SquadId, Squads};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "raid weapon stash";
+/// weapons in the stash and their value
+const STASH_WEAPONS: [(&str, u32); 3] = [("MP5", 1200), ("AK-47", 1800), ("Sniper Rifle", 3000)];
+const CODES: std::ops::RangeInclusive<u32> = 1000..=9999;
+
+// Plugin
+/// contract to open a weapon stash locked with a code that is written on a note nearby
+pub struct RaidWeaponStashPlugin;
+
+impl Plugin for RaidWeaponStashPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_raid_weapon_stash)
+            .add_systems(
+                Update,
+                (spawn_weapon_stash, weapon_stash_unlocked)
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_raid_weapon_stash);
+    }
+}
+
+// Components
+
+/// where the stash of the contract with the same `ContractId` is placed
+#[derive(Component, Debug)]
+pub struct WeaponStashSpawn;
+
+/// loot cache the note with the code of the contract with the same `ContractId` might be in
+#[derive(Component, Debug)]
+pub struct StashNoteCache;
+
+#[derive(Component, Debug)]
+pub struct WeaponStash;
+
+// Resources
+
+// Events
+
+// Systems
+fn start_raid_weapon_stash(mut _commands: Commands) {
+    debug!("starting {}", NAME);
+}
+
+/// locks the stash with a random code and hides the note with it in one of the caches
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn spawn_weapon_stash(
+    mut commands: Commands,
+    mut accepted: EventReader<ContractAccepted>,
+    contracts: Res<Contracts>,
+    spawns: Query<(Entity, &ContractId, &GlobalTransform), With<WeaponStashSpawn>>,
+    caches: Query<(Entity, &ContractId), (With<StashNoteCache>, With<Inventory>)>,
+    mut stow_loot: EventWriter<StowLoot>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for event in accepted.read() {
+        let is_stash_contract = contracts
+            .map
+            .get(&event.contract_id)
+            .is_some_and(|contract| contract.contract_type == ContractType::RaidWeaponStash);
+        if !is_stash_contract {
+            continue;
+        }
+        let Some((spawn, _, transform)) = spawns
+            .iter()
+            .find(|(_, contract_id, _)| **contract_id == event.contract_id)
+        else {
+            warn!("no weapon stash spawn for contract {:?}", event.contract_id);
+            continue;
+        };
+        let mut rng = rand::thread_rng();
+        let code = rng.gen_range(CODES);
+        let position = transform.translation();
+        debug!(
+            "weapon stash of contract {:?} at {}",
+            event.contract_id, position
+        );
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Cuboid::new(1.5, 0.8, 0.6))),
+                MeshMaterial3d(materials.add(Color::srgb(0.2, 0.3, 0.2))),
+                Transform::from_translation(position + Vec3::Y * 0.4),
+            ))
+            .insert(Name::new("Weapon Stash"))
+            .insert(WeaponStash)
+            .insert(Inventory)
+            .insert(LootCacheState::Locked)
+            .insert(Lock { code })
+            .insert(Keypad)
+            .insert(Interactable)
+            .insert(WeaponSlots(STASH_WEAPONS.len() as u8))
+            .insert(ItemSlots(2))
+            .insert(event.contract_id)
+            .with_children(|parent| {
+                for (slot, (name, price)) in STASH_WEAPONS.iter().enumerate() {
+                    parent
+                        .spawn(Name::new(*name))
+                        .insert(Loot)
+                        .insert(Interactable)
+                        .insert(LootName(name.to_string()))
+                        .insert(WeaponSlot(slot as u8))
+                        .insert(LootType::Weapon)
+                        .insert(Price(*price))
+                        .insert(Rarity::Rare);
+                }
+            });
+        commands.entity(spawn).despawn_recursive();
+        commands
+            .spawn(Transform::from_translation(position))
+            .insert(Name::new("Weapon Stash General Area"))
+            .insert(ContractGeneralArea::default())
+            .insert(event.contract_id);
+
+        let candidates: Vec<Entity> = caches
+            .iter()
+            .filter(|(_, contract_id)| **contract_id == event.contract_id)
+            .map(|(cache, _)| cache)
+            .collect();
+        let Some(cache) = candidates.choose(&mut rng) else {
+            warn!("no note cache for contract {:?}", event.contract_id);
+            continue;
+        };
+        let note = commands
+            .spawn(Name::new("Note"))
+            .insert(Loot)
+            .insert(Interactable)
+            .insert(LootName(note_text(code)))
+            .insert(LootType::Item(ItemType::Item))
+            .insert(Price(0))
+            .insert(Rarity::Regular)
+            .id();
+        stow_loot.send(StowLoot {
+            stowing_entity: *cache,
+            loot: note,
+            loot_type: LootType::Item(ItemType::Item),
+        });
+    }
+}
+
+/// the contract is done once a member of the contract squad entered the code
+fn weapon_stash_unlocked(
+    mut unlocked: EventReader<Unlocked>,
+    stashes: Query<&ContractId, With<WeaponStash>>,
+    operators: Query<&SquadId>,
+    squads: Res<Squads>,
+    mut contracts: ResMut<Contracts>,
+    mut completed: EventWriter<ContractCompleted>,
+) {
+    for event in unlocked.read() {
+        let Ok(contract_id) = stashes.get(event.unlocked_entity) else {
+            continue;
+        };
+        let Ok(squad_id) = operators.get(event.operator_entity) else {
+            continue;
+        };
+        if contract_squad(&squads, *contract_id) != Some(squad_id) {
+            continue;
+        }
+        let Some(contract) = contracts.map.get_mut(contract_id) else {
+            continue;
+        };
+        if let ContractState::RaidWeaponStash(mut state @ RaidWeaponStashState::Started) =
+            contract.contract_state
+        {
+            debug!("weapon stash of contract {:?} unlocked", contract_id);
+            contract.contract_state = state.next();
+            completed.send(ContractCompleted {
+                contract_id: *contract_id,
+            });
+        }
+    }
+}
+
+fn bye_raid_weapon_stash(mut commands: Commands, stashes: Query<Entity, With<WeaponStash>>) {
+    debug!("stopping {}", NAME);
+    for stash in stashes.iter() {
+        commands.entity(stash).despawn_recursive();
+    }
+}
+
+// helper functions
+
+fn note_text(code: u32) -> String {
+    format!("Note \"stash {}\"", code)
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::contracts::insert_held_contract;
+
+    #[test]
+    fn should_spawn_locked_stash_and_note_with_code() {
+        // given
+        let mut app = App::new();
+        app.init_resource::<Assets<Mesh>>();
+        app.init_resource::<Assets<StandardMaterial>>();
+        app.add_event::<ContractAccepted>();
+        app.add_event::<StowLoot>();
+        app.add_systems(Update, spawn_weapon_stash);
+        let contract_id = ContractId(21);
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::RaidWeaponStash,
+            ContractState::RaidWeaponStash(RaidWeaponStashState::Started),
+        );
+        app.world_mut()
+            .spawn((WeaponStashSpawn, contract_id, GlobalTransform::default()));
+        let cache = app
+            .world_mut()
+            .spawn((StashNoteCache, Inventory, contract_id))
+            .id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<ContractAccepted>>()
+            .send(ContractAccepted { contract_id });
+        app.update();
+
+        // then
+        let mut stashes = app
+            .world_mut()
+            .query_filtered::<(&Lock, &Children), With<WeaponStash>>();
+        let (lock, weapons) = stashes.single(app.world());
+        assert!(CODES.contains(&lock.code));
+        assert_eq!(STASH_WEAPONS.len(), weapons.len());
+        let stow_events = app.world().resource::<Events<StowLoot>>();
+        let mut stow_reader = stow_events.get_cursor();
+        let stow = stow_reader.read(stow_events).next().unwrap();
+        assert_eq!(cache, stow.stowing_entity);
+        assert_eq!(
+            note_text(lock.code),
+            app.world().get::<LootName>(stow.loot).unwrap().0
+        );
+    }
+
+    #[test]
+    fn should_complete_contract_when_contract_squad_unlocked_stash() {
+        // given
+        let mut app = App::new();
+        app.add_event::<Unlocked>();
+        app.add_event::<ContractCompleted>();
+        app.add_systems(Update, weapon_stash_unlocked);
+        let contract_id = ContractId(22);
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::RaidWeaponStash,
+            ContractState::RaidWeaponStash(RaidWeaponStashState::Started),
+        );
+        let stash = app.world_mut().spawn((WeaponStash, contract_id)).id();
+        let operator = app.world_mut().spawn(SquadId(1)).id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<Unlocked>>()
+            .send(Unlocked {
+                unlocked_entity: stash,
+                operator_entity: operator,
+            });
+        app.update();
+
+        // then
+        assert_eq!(
+            ContractState::RaidWeaponStash(RaidWeaponStashState::StashUnlocked),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        let completed_events = app.world().resource::<Events<ContractCompleted>>();
+        let mut completed_reader = completed_events.get_cursor();
+        assert_eq!(
+            vec![&ContractCompleted { contract_id }],
+            completed_reader.read(completed_events).collect::<Vec<_>>()
+        );
+    }
+}
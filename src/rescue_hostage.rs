@@ -0,0 +1,452 @@
+use bevy::app::Plugin;
+use bevy::math::bounding::Aabb3d;
+use bevy::utils::HashMap;
+
+use crate::contracts::{
+    contract_squad, ended_contracts, ContractAccepted, ContractCancelled, ContractCompleted,
+    ContractFailed, ContractGeneralArea, ContractId, ContractSpotlight, ContractState,
+    ContractStateMachine, ContractType, Contracts, CurrentContractObjective, FailContract,
+    RescueHostageState,
+};
+use crate::damage::HurtBox;
+use crate::death::EntityDied;
+use crate::exfil::{ExfilArea, Operator};
+use crate::follow::{FollowBehaviour, FollowTarget};
+use crate::health::Health;
+use crate::interaction::{Interact, Interactable};
+use crate::raid::{Enemy, Volume};
+use crate::squad::{SquadId, Squads};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "rescue hostage";
+const HOSTAGE_SPEED: f32 = 3.5;
+/// the hostage stays this close behind the operator
+const HOSTAGE_KEEP_DISTANCE: f32 = 1.5;
+/// a hostage this close to an exfil area is rescued
+const EXFIL_RESCUE_DISTANCE: f32 = 5.0;
+/// guard positions relative to the hostage
+const GUARD_OFFSETS: [Vec3; 2] = [Vec3::new(2.0, 0.0, 2.0), Vec3::new(-2.0, 0.0, 3.0)];
+/// the general area is not centered on the building
+const GENERAL_AREA_OFFSET: Vec3 = Vec3::new(-5.0, 0.0, 7.0);
+
+// Plugin
+/// contract to free a hostage from a guarded building and escort it alive to safety
+pub struct RescueHostagePlugin;
+
+impl Plugin for RescueHostagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_rescue_hostage)
+            .add_systems(
+                Update,
+                (
+                    spawn_hostage,
+                    free_hostage,
+                    rescue_hostage,
+                    hostage_died,
+                    hostage_contract_ended,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_rescue_hostage);
+    }
+}
+
+// Components
+
+/// where the hostage of the contract with the same `ContractId` is held
+#[derive(Component, Debug)]
+pub struct HostageSpawn;
+
+#[derive(Component, Debug)]
+pub struct Hostage;
+
+#[derive(Component, Debug)]
+pub struct HostageGuard;
+
+/// together with a `Volume`, hostages brought in here are rescued
+#[derive(Component, Debug)]
+pub struct RescueZone;
+
+// Resources
+
+/// contract of every hostage, the body is gone once it died
+#[derive(Resource, Default)]
+struct Hostages {
+    map: HashMap<Entity, ContractId>,
+}
+
+// Events
+
+// Systems
+fn start_rescue_hostage(mut commands: Commands) {
+    debug!("starting {}", NAME);
+    commands.init_resource::<Hostages>();
+}
+
+fn spawn_hostage(
+    mut commands: Commands,
+    mut accepted: EventReader<ContractAccepted>,
+    contracts: Res<Contracts>,
+    hostages: Option<ResMut<Hostages>>,
+    spawns: Query<(Entity, &ContractId, &GlobalTransform), With<HostageSpawn>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(mut hostages) = hostages else {
+        return;
+    };
+    for event in accepted.read() {
+        let is_hostage_contract = contracts
+            .map
+            .get(&event.contract_id)
+            .is_some_and(|contract| contract.contract_type == ContractType::RescueHostage);
+        if !is_hostage_contract {
+            continue;
+        }
+        for (spawn, _, transform) in spawns
+            .iter()
+            .filter(|(_, contract_id, _)| **contract_id == event.contract_id)
+        {
+            let position = transform.translation();
+            debug!(
+                "hostage of contract {:?} at {}",
+                event.contract_id, position
+            );
+            let hostage = commands
+                .spawn((
+                    Mesh3d(meshes.add(Capsule3d::new(0.25, 1.5))),
+                    MeshMaterial3d(materials.add(Color::srgb(0.9, 0.9, 0.9))),
+                    Transform::from_translation(position + Vec3::Y),
+                ))
+                .insert(Name::new("Hostage"))
+                .insert(Hostage)
+                .insert(Interactable)
+                .insert(event.contract_id)
+                .insert(Health::default())
+                .insert(HurtBox(Aabb3d::new(
+                    Vec3::default(),
+                    Vec3::new(0.25, 1.0, 0.25),
+                )))
+                .id();
+            hostages.map.insert(hostage, event.contract_id);
+            for offset in GUARD_OFFSETS {
+                commands
+                    .spawn((
+                        Mesh3d(meshes.add(Capsule3d::new(0.25, 1.5))),
+                        MeshMaterial3d(materials.add(Color::srgb(0.5, 0.1, 0.1))),
+                        Transform::from_translation(position + offset + Vec3::Y),
+                    ))
+                    .insert(Name::new("Hostage Guard"))
+                    .insert(Enemy)
+                    .insert(HostageGuard)
+                    .insert(Health::default())
+                    .insert(HurtBox(Aabb3d::new(
+                        Vec3::default(),
+                        Vec3::new(0.25, 1.0, 0.25),
+                    )));
+            }
+            commands
+                .spawn(Transform::from_translation(position + GENERAL_AREA_OFFSET))
+                .insert(Name::new("Hostage General Area"))
+                .insert(ContractGeneralArea::default())
+                .insert(event.contract_id);
+            commands.entity(spawn).despawn_recursive();
+        }
+    }
+}
+
+/// only a member of the contract squad can free the hostage, it follows that operator from then on
+fn free_hostage(
+    mut commands: Commands,
+    mut interactions: EventReader<Interact>,
+    hostages: Query<&ContractId, (With<Hostage>, Without<FollowTarget>)>,
+    operators: Query<&SquadId, With<Operator>>,
+    squads: Res<Squads>,
+    mut contracts: ResMut<Contracts>,
+) {
+    for interaction in interactions.read() {
+        let Ok(contract_id) = hostages.get(interaction.interaction_entity) else {
+            continue;
+        };
+        let Ok(squad_id) = operators.get(interaction.operator_entity) else {
+            continue;
+        };
+        if contract_squad(&squads, *contract_id) != Some(squad_id) {
+            debug!("hostage does not trust squad {:?}", squad_id);
+            continue;
+        }
+        let Some(contract) = contracts.map.get_mut(contract_id) else {
+            continue;
+        };
+        if let ContractState::RescueHostage(mut state @ RescueHostageState::Started) =
+            contract.contract_state
+        {
+            debug!("hostage freed by {}", interaction.operator_entity);
+            contract.contract_state = state.next();
+            commands
+                .entity(interaction.interaction_entity)
+                .remove::<Interactable>()
+                .insert(FollowTarget(interaction.operator_entity))
+                .insert(FollowBehaviour {
+                    speed: HOSTAGE_SPEED,
+                    keep_distance: HOSTAGE_KEEP_DISTANCE,
+                })
+                .insert(ContractSpotlight)
+                .insert(CurrentContractObjective);
+        }
+    }
+}
+
+/// a freed hostage inside a rescue zone or close to an exfil is safe
+#[allow(clippy::type_complexity)]
+fn rescue_hostage(
+    mut commands: Commands,
+    freed: Query<(Entity, &ContractId, &GlobalTransform), (With<Hostage>, With<FollowTarget>)>,
+    rescue_zones: Query<(&Volume, &GlobalTransform), With<RescueZone>>,
+    exfil_areas: Query<&GlobalTransform, With<ExfilArea>>,
+    hostages: Option<ResMut<Hostages>>,
+    mut contracts: ResMut<Contracts>,
+    mut completed: EventWriter<ContractCompleted>,
+) {
+    let Some(mut hostages) = hostages else {
+        return;
+    };
+    for (hostage, contract_id, transform) in freed.iter() {
+        let position = transform.translation();
+        let in_rescue_zone = rescue_zones
+            .iter()
+            .any(|(volume, zone)| volume.contains(zone.translation(), position));
+        let at_exfil = exfil_areas
+            .iter()
+            .any(|area| area.translation().distance(position) <= EXFIL_RESCUE_DISTANCE);
+        if !in_rescue_zone && !at_exfil {
+            continue;
+        }
+        let Some(contract) = contracts.map.get_mut(contract_id) else {
+            continue;
+        };
+        if let ContractState::RescueHostage(mut state @ RescueHostageState::Freed) =
+            contract.contract_state
+        {
+            debug!("hostage of contract {:?} rescued", contract_id);
+            contract.contract_state = state.next();
+            hostages.map.remove(&hostage);
+            commands.entity(hostage).despawn_recursive();
+            completed.send(ContractCompleted {
+                contract_id: *contract_id,
+            });
+        }
+    }
+}
+
+fn hostage_died(
+    mut died: EventReader<EntityDied>,
+    hostages: Option<ResMut<Hostages>>,
+    mut fail: EventWriter<FailContract>,
+) {
+    let Some(mut hostages) = hostages else {
+        return;
+    };
+    for event in died.read() {
+        if let Some(contract_id) = hostages.map.remove(&event.death) {
+            debug!("hostage of contract {:?} died", contract_id);
+            fail.send(FailContract { contract_id });
+        }
+    }
+}
+
+/// the hostage of a failed or cancelled contract stays where it is and can't be freed anymore
+fn hostage_contract_ended(
+    mut commands: Commands,
+    mut failed: EventReader<ContractFailed>,
+    mut cancelled: EventReader<ContractCancelled>,
+    hostages: Option<ResMut<Hostages>>,
+) {
+    let Some(mut hostages) = hostages else {
+        return;
+    };
+    let ended = ended_contracts(&mut failed, &mut cancelled);
+    hostages.map.retain(|hostage, contract_id| {
+        if !ended.contains(contract_id) {
+            return true;
+        }
+        debug!("hostage of contract {:?} is left behind", contract_id);
+        commands
+            .entity(*hostage)
+            .remove::<Interactable>()
+            .remove::<FollowTarget>()
+            .remove::<FollowBehaviour>()
+            .remove::<ContractSpotlight>()
+            .remove::<CurrentContractObjective>();
+        false
+    });
+}
+
+#[allow(clippy::type_complexity)]
+fn bye_rescue_hostage(
+    mut commands: Commands,
+    entities: Query<Entity, Or<(With<Hostage>, With<HostageGuard>)>>,
+) {
+    debug!("stopping {}", NAME);
+    commands.remove_resource::<Hostages>();
+    for entity in entities.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// helper functions
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::contracts::insert_held_contract;
+    use crate::death::DeathKind;
+
+    fn hostage_app(state: RescueHostageState) -> (App, ContractId) {
+        let mut app = App::new();
+        let contract_id = ContractId(11);
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::RescueHostage,
+            ContractState::RescueHostage(state),
+        );
+        app.init_resource::<Hostages>();
+        (app, contract_id)
+    }
+
+    #[test]
+    fn should_free_hostage_for_contract_squad_only() {
+        // given
+        let (mut app, contract_id) = hostage_app(RescueHostageState::Started);
+        app.add_event::<Interact>();
+        app.add_systems(Update, free_hostage);
+        let hostage = app.world_mut().spawn((Hostage, contract_id)).id();
+        let stranger = app.world_mut().spawn((Operator, SquadId(2))).id();
+        let rescuer = app.world_mut().spawn((Operator, SquadId(1))).id();
+
+        // when
+        for operator_entity in [stranger, rescuer] {
+            app.world_mut()
+                .resource_mut::<Events<Interact>>()
+                .send(Interact {
+                    interaction_entity: hostage,
+                    operator_entity,
+                });
+        }
+        app.update();
+
+        // then
+        assert_eq!(rescuer, app.world().get::<FollowTarget>(hostage).unwrap().0);
+        assert_eq!(
+            ContractState::RescueHostage(RescueHostageState::Freed),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+    }
+
+    #[test]
+    fn should_rescue_hostage_in_rescue_zone() {
+        // given
+        let (mut app, contract_id) = hostage_app(RescueHostageState::Freed);
+        app.add_event::<ContractCompleted>();
+        app.add_systems(Update, rescue_hostage);
+        let operator = app.world_mut().spawn(Operator).id();
+        let hostage = app
+            .world_mut()
+            .spawn((
+                Hostage,
+                contract_id,
+                FollowTarget(operator),
+                GlobalTransform::from_xyz(10.0, 1.0, 0.0),
+            ))
+            .id();
+        app.world_mut().spawn((
+            RescueZone,
+            Volume(Aabb3d::new(Vec3::ZERO, Vec3::splat(2.0))),
+            GlobalTransform::from_xyz(11.0, 0.0, 0.0),
+        ));
+
+        // when
+        app.update();
+
+        // then
+        assert!(app.world().get_entity(hostage).is_err());
+        let completed_events = app.world().resource::<Events<ContractCompleted>>();
+        let mut completed_reader = completed_events.get_cursor();
+        assert_eq!(
+            vec![&ContractCompleted { contract_id }],
+            completed_reader.read(completed_events).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_fail_contract_when_hostage_died() {
+        // given
+        let (mut app, contract_id) = hostage_app(RescueHostageState::Freed);
+        app.add_event::<EntityDied>();
+        app.add_event::<FailContract>();
+        app.add_systems(Update, hostage_died);
+        let hostage = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .resource_mut::<Hostages>()
+            .map
+            .insert(hostage, contract_id);
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<EntityDied>>()
+            .send(EntityDied {
+                death: hostage,
+                killer: None,
+                killer_squad: None,
+                death_squad: None,
+                death_kind: DeathKind::Other,
+                death_name: None,
+                weapon: None,
+                assists: vec![],
+            });
+        app.update();
+
+        // then
+        let fail_events = app.world().resource::<Events<FailContract>>();
+        let mut fail_reader = fail_events.get_cursor();
+        assert_eq!(
+            vec![&FailContract { contract_id }],
+            fail_reader.read(fail_events).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_stop_following_when_contract_cancelled() {
+        // given
+        let (mut app, contract_id) = hostage_app(RescueHostageState::Freed);
+        app.add_event::<ContractFailed>();
+        app.add_event::<ContractCancelled>();
+        app.add_systems(Update, hostage_contract_ended);
+        let operator = app.world_mut().spawn(Operator).id();
+        let hostage = app
+            .world_mut()
+            .spawn((Hostage, contract_id, FollowTarget(operator)))
+            .id();
+        app.world_mut()
+            .resource_mut::<Hostages>()
+            .map
+            .insert(hostage, contract_id);
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<ContractCancelled>>()
+            .send(ContractCancelled { contract_id });
+        app.update();
+
+        // then
+        assert!(app.world().get::<FollowTarget>(hostage).is_none());
+        assert!(app.world().resource::<Hostages>().map.is_empty());
+    }
+}
@@ -0,0 +1,245 @@
+use bevy::app::Plugin;
+use rand::seq::SliceRandom;
+
+use crate::contracts::{
+    contract_squad, ContractAccepted, ContractCompleted, ContractGeneralArea, ContractId,
+    ContractState, ContractStateMachine, ContractType, Contracts, SecureIntelState,
+};
+use crate::interaction::Interactable;
+use crate::inventory::{Inventory, StowLoot, StowedLoot};
+use crate::loot::{ContractItem, Loot, LootName, LootType, Price, Rarity};
+use crate::squad::{SquadId, Squads};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "secure intel";
+/// what the intel turns out to be
+const INTEL_NAMES: [&str; 3] = ["Laptop", "Documents", "Hard Drive"];
+/// value of the intel
+const INTEL_PRICE: u32 = 500;
+
+// Plugin
+/// contract to find intel hidden in one of the caches of an area and bring it out
+pub struct SecureIntelPlugin;
+
+impl Plugin for SecureIntelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_secure_intel)
+            .add_systems(
+                Update,
+                (hide_intel, intel_stowed)
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_secure_intel);
+    }
+}
+
+// Components
+
+/// loot cache the intel of the contract with the same `ContractId` might be hidden in
+#[derive(Component, Debug)]
+pub struct IntelCache;
+
+/// intel of a secure intel contract
+#[derive(Component, Debug)]
+pub struct Intel;
+
+// Resources
+
+// Events
+
+// Systems
+fn start_secure_intel(mut _commands: Commands) {
+    debug!("starting {}", NAME);
+}
+
+/// the intel goes into a random cache, the squad is only told the area around all caches
+#[allow(clippy::type_complexity)]
+fn hide_intel(
+    mut commands: Commands,
+    mut accepted: EventReader<ContractAccepted>,
+    contracts: Res<Contracts>,
+    caches: Query<(Entity, &ContractId, &GlobalTransform), (With<IntelCache>, With<Inventory>)>,
+    mut stow_loot: EventWriter<StowLoot>,
+) {
+    for event in accepted.read() {
+        let is_intel_contract = contracts
+            .map
+            .get(&event.contract_id)
+            .is_some_and(|contract| contract.contract_type == ContractType::SecureIntel);
+        if !is_intel_contract {
+            continue;
+        }
+        let candidates: Vec<(Entity, Vec3)> = caches
+            .iter()
+            .filter(|(_, contract_id, _)| **contract_id == event.contract_id)
+            .map(|(cache, _, transform)| (cache, transform.translation()))
+            .collect();
+        let mut rng = rand::thread_rng();
+        let Some((cache, _)) = candidates.choose(&mut rng) else {
+            warn!("no intel cache for contract {:?}", event.contract_id);
+            continue;
+        };
+        let name = INTEL_NAMES.choose(&mut rng).unwrap_or(&INTEL_NAMES[0]);
+        debug!("{} of contract {:?} in {}", name, event.contract_id, cache);
+        let intel = commands
+            .spawn(Name::new(*name))
+            .insert(Loot)
+            .insert(Interactable)
+            .insert(LootName(name.to_string()))
+            .insert(LootType::Intel)
+            .insert(Price(INTEL_PRICE))
+            .insert(Rarity::Rare)
+            .insert(ContractItem)
+            .insert(Intel)
+            .insert(event.contract_id)
+            .id();
+        stow_loot.send(StowLoot {
+            stowing_entity: *cache,
+            loot: intel,
+            loot_type: LootType::Intel,
+        });
+        commands
+            .spawn(Transform::from_translation(general_area_center(
+                &candidates,
+            )))
+            .insert(Name::new("Intel General Area"))
+            .insert(ContractGeneralArea::default())
+            .insert(event.contract_id);
+    }
+}
+
+/// the contract is done as soon as a member of the contract squad has the intel in the backpack
+fn intel_stowed(
+    mut stowed: EventReader<StowedLoot>,
+    intel: Query<&ContractId, With<Intel>>,
+    backpacks: Query<&Parent, With<Inventory>>,
+    operators: Query<&SquadId>,
+    squads: Res<Squads>,
+    mut contracts: ResMut<Contracts>,
+    mut completed: EventWriter<ContractCompleted>,
+) {
+    for event in stowed.read() {
+        let Ok(contract_id) = intel.get(event.loot) else {
+            continue;
+        };
+        let Some(squad_id) = backpacks
+            .get(event.stowing_entity)
+            .ok()
+            .and_then(|operator| operators.get(operator.get()).ok())
+        else {
+            continue;
+        };
+        if contract_squad(&squads, *contract_id) != Some(squad_id) {
+            continue;
+        }
+        let Some(contract) = contracts.map.get_mut(contract_id) else {
+            continue;
+        };
+        if let ContractState::SecureIntel(mut state @ SecureIntelState::Started) =
+            contract.contract_state
+        {
+            debug!("intel of contract {:?} secured", contract_id);
+            contract.contract_state = state.next();
+            completed.send(ContractCompleted {
+                contract_id: *contract_id,
+            });
+        }
+    }
+}
+
+/// the intel is cleaned up along with the inventory it is in
+fn bye_secure_intel(mut _commands: Commands) {
+    debug!("stopping {}", NAME);
+}
+
+// helper functions
+
+fn general_area_center(caches: &[(Entity, Vec3)]) -> Vec3 {
+    let sum: Vec3 = caches.iter().map(|(_, position)| *position).sum();
+    sum / caches.len().max(1) as f32
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::contracts::insert_held_contract;
+    use crate::exfil::Operator;
+
+    #[test]
+    fn should_center_general_area_between_caches() {
+        // given
+        let mut world = World::new();
+        let caches = vec![
+            (world.spawn_empty().id(), Vec3::new(0.0, 0.0, 0.0)),
+            (world.spawn_empty().id(), Vec3::new(10.0, 0.0, 4.0)),
+        ];
+
+        // when
+        let center = general_area_center(&caches);
+
+        // then
+        assert_eq!(Vec3::new(5.0, 0.0, 2.0), center);
+    }
+
+    #[test]
+    fn should_secure_intel_when_stowed_by_contract_squad() {
+        // given
+        let mut app = App::new();
+        app.add_event::<StowedLoot>();
+        app.add_event::<ContractCompleted>();
+        app.add_systems(Update, intel_stowed);
+        let contract_id = ContractId(9);
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::SecureIntel,
+            ContractState::SecureIntel(SecureIntelState::Started),
+        );
+        let intel = app.world_mut().spawn((Loot, Intel, contract_id)).id();
+        let cache = app.world_mut().spawn(Inventory).id();
+        let operator = app.world_mut().spawn((Operator, SquadId(1))).id();
+        let backpack = app.world_mut().spawn(Inventory).set_parent(operator).id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<StowedLoot>>()
+            .send(StowedLoot {
+                stowing_entity: cache,
+                loot: intel,
+            });
+        app.update();
+
+        // then
+        assert_eq!(
+            ContractState::SecureIntel(SecureIntelState::Started),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<StowedLoot>>()
+            .send(StowedLoot {
+                stowing_entity: backpack,
+                loot: intel,
+            });
+        app.update();
+
+        // then
+        assert_eq!(
+            ContractState::SecureIntel(SecureIntelState::IntelSecured),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        let completed_events = app.world().resource::<Events<ContractCompleted>>();
+        let mut completed_reader = completed_events.get_cursor();
+        assert_eq!(
+            vec![&ContractCompleted { contract_id }],
+            completed_reader.read(completed_events).collect::<Vec<_>>()
+        );
+    }
+}
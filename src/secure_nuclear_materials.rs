@@ -0,0 +1,582 @@
+use bevy::app::Plugin;
+use bevy::math::bounding::Aabb3d;
+use bevy::utils::{HashMap, HashSet};
+
+use crate::contracts::{
+    contract_squad, ended_contracts, ContractAccepted, ContractCancelled, ContractCompleted,
+    ContractFailed, ContractGeneralArea, ContractId, ContractState, ContractStateMachine,
+    ContractType, Contracts, SecureNuclearMaterialsState,
+};
+use crate::damage::HurtBox;
+use crate::exfil::{ExfilExitedAO, Operator};
+use crate::follow::{FollowBehaviour, FollowTarget};
+use crate::health::Health;
+use crate::interaction::Interactable;
+use crate::inventory::{Inventory, ItemSlot, ItemSlots, StowedLoot};
+use crate::loot::{
+    ContractItem, ItemType, Loot, LootCacheState, LootName, LootType, Price, Rarity,
+};
+use crate::radiation::RadiationSource;
+use crate::raid::{Enemy, Volume};
+use crate::squad::{SquadId, Squads};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "secure nuclear materials";
+/// value of the nuclear material
+const MATERIAL_PRICE: u32 = 2500;
+/// the carrier is irradiated like in a radiation zone of this intensity
+const MATERIAL_RADIATION_INTENSITY: i32 = 2;
+/// guard positions relative to the container
+const GUARD_OFFSETS: [Vec3; 4] = [
+    Vec3::new(3.0, 0.0, 0.0),
+    Vec3::new(-3.0, 0.0, 0.0),
+    Vec3::new(0.0, 0.0, 3.0),
+    Vec3::new(0.0, 0.0, -3.0),
+];
+const GUARD_SPEED: f32 = 3.0;
+/// guards chasing the carrier stop this close to it
+const GUARD_KEEP_DISTANCE: f32 = 6.0;
+
+// Plugin
+/// contract to take nuclear material out of a guarded container and bring it to a drop-off or
+/// out of the raid, it irradiates its carrier and gives away the carrier's position
+pub struct SecureNuclearMaterialsPlugin;
+
+impl Plugin for SecureNuclearMaterialsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_secure_nuclear_materials)
+            .add_systems(
+                Update,
+                (
+                    spawn_nuclear_container,
+                    nuclear_material_stowed,
+                    reveal_carrier,
+                    drop_off_nuclear_material,
+                    exfil_with_nuclear_material,
+                    nuclear_contract_ended,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_secure_nuclear_materials);
+    }
+}
+
+// Components
+
+/// where the container of the contract with the same `ContractId` is placed
+#[derive(Component, Debug)]
+pub struct NuclearContainerSpawn;
+
+/// together with a `Volume`, the nuclear material of the contract with the same `ContractId` is
+/// dropped off here
+#[derive(Component, Debug)]
+pub struct NuclearDropOff;
+
+#[derive(Component, Debug)]
+pub struct NuclearContainer;
+
+#[derive(Component, Debug)]
+pub struct NuclearGuard;
+
+/// nuclear material of a secure nuclear materials contract
+#[derive(Component, Debug)]
+pub struct NuclearMaterial;
+
+/// operator whose position is given away by the nuclear material in the backpack
+#[derive(Component, Debug)]
+pub struct RadiationSignature;
+
+// Resources
+
+/// contract of the nuclear material still in the raid
+#[derive(Resource, Default)]
+struct NuclearMaterials {
+    map: HashMap<Entity, ContractId>,
+}
+
+// Events
+
+// Systems
+fn start_secure_nuclear_materials(mut commands: Commands) {
+    debug!("starting {}", NAME);
+    commands.init_resource::<NuclearMaterials>();
+}
+
+/// the material goes into a container surrounded by guards
+fn spawn_nuclear_container(
+    mut commands: Commands,
+    mut accepted: EventReader<ContractAccepted>,
+    contracts: Res<Contracts>,
+    materials_in_raid: Option<ResMut<NuclearMaterials>>,
+    spawns: Query<(Entity, &ContractId, &GlobalTransform), With<NuclearContainerSpawn>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(mut materials_in_raid) = materials_in_raid else {
+        return;
+    };
+    for event in accepted.read() {
+        let is_nuclear_contract = contracts
+            .map
+            .get(&event.contract_id)
+            .is_some_and(|contract| contract.contract_type == ContractType::SecureNuclearMaterials);
+        if !is_nuclear_contract {
+            continue;
+        }
+        let Some((spawn, _, transform)) = spawns
+            .iter()
+            .find(|(_, contract_id, _)| **contract_id == event.contract_id)
+        else {
+            warn!(
+                "no nuclear container spawn for contract {:?}",
+                event.contract_id
+            );
+            continue;
+        };
+        let position = transform.translation();
+        debug!(
+            "nuclear container of contract {:?} at {}",
+            event.contract_id, position
+        );
+        let mut material = None;
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+                MeshMaterial3d(materials.add(Color::srgb(0.8, 0.7, 0.1))),
+                Transform::from_translation(position + Vec3::Y * 0.5),
+            ))
+            .insert(Name::new("Nuclear Container"))
+            .insert(NuclearContainer)
+            .insert(Inventory)
+            .insert(LootCacheState::Closed)
+            .insert(ItemSlots(1))
+            .insert(Interactable)
+            .insert(event.contract_id)
+            .with_children(|parent| {
+                material = Some(
+                    parent
+                        .spawn(Name::new("Nuclear Material"))
+                        .insert(Loot)
+                        .insert(Interactable)
+                        .insert(LootName("Nuclear Material".to_string()))
+                        .insert(ItemSlot(0))
+                        .insert(LootType::Item(ItemType::Item))
+                        .insert(Price(MATERIAL_PRICE))
+                        .insert(Rarity::Rare)
+                        .insert(ContractItem)
+                        .insert(NuclearMaterial)
+                        .insert(RadiationSource {
+                            intensity: MATERIAL_RADIATION_INTENSITY,
+                        })
+                        .insert(event.contract_id)
+                        .id(),
+                );
+            });
+        if let Some(material) = material {
+            materials_in_raid.map.insert(material, event.contract_id);
+        }
+        for offset in GUARD_OFFSETS {
+            commands
+                .spawn((
+                    Mesh3d(meshes.add(Capsule3d::new(0.25, 1.5))),
+                    MeshMaterial3d(materials.add(Color::srgb(0.5, 0.1, 0.1))),
+                    Transform::from_translation(position + offset + Vec3::Y),
+                ))
+                .insert(Name::new("Nuclear Guard"))
+                .insert(Enemy)
+                .insert(NuclearGuard)
+                .insert(event.contract_id)
+                .insert(Health::default())
+                .insert(HurtBox(Aabb3d::new(
+                    Vec3::default(),
+                    Vec3::new(0.25, 1.0, 0.25),
+                )));
+        }
+        commands
+            .spawn(Transform::from_translation(position))
+            .insert(Name::new("Nuclear Container General Area"))
+            .insert(ContractGeneralArea::default())
+            .insert(event.contract_id);
+        commands.entity(spawn).despawn_recursive();
+    }
+}
+
+/// the material is recovered once a member of the contract squad has it in the backpack
+fn nuclear_material_stowed(
+    mut stowed: EventReader<StowedLoot>,
+    nuclear_materials: Query<&ContractId, With<NuclearMaterial>>,
+    backpacks: Query<&Parent, With<Inventory>>,
+    operators: Query<&SquadId>,
+    squads: Res<Squads>,
+    mut contracts: ResMut<Contracts>,
+) {
+    for event in stowed.read() {
+        let Ok(contract_id) = nuclear_materials.get(event.loot) else {
+            continue;
+        };
+        let Some(squad_id) = backpacks
+            .get(event.stowing_entity)
+            .ok()
+            .and_then(|operator| operators.get(operator.get()).ok())
+        else {
+            continue;
+        };
+        if contract_squad(&squads, *contract_id) != Some(squad_id) {
+            continue;
+        }
+        let Some(contract) = contracts.map.get_mut(contract_id) else {
+            continue;
+        };
+        if let ContractState::SecureNuclearMaterials(
+            mut state @ SecureNuclearMaterialsState::Started,
+        ) = contract.contract_state
+        {
+            debug!("nuclear material of contract {:?} recovered", contract_id);
+            contract.contract_state = state.next();
+        }
+    }
+}
+
+/// whoever carries the material gives away its position and is chased by the guards, the
+/// radiation damage comes from the `RadiationSource` on the material
+fn reveal_carrier(
+    mut commands: Commands,
+    materials_in_raid: Option<Res<NuclearMaterials>>,
+    nuclear_materials: Query<&Parent, With<NuclearMaterial>>,
+    backpacks: Query<&Parent, With<Inventory>>,
+    operators: Query<Has<RadiationSignature>, With<Operator>>,
+    signatures: Query<Entity, With<RadiationSignature>>,
+    guards: Query<Entity, With<NuclearGuard>>,
+) {
+    let Some(materials_in_raid) = materials_in_raid else {
+        return;
+    };
+    let carriers: HashSet<Entity> = materials_in_raid
+        .map
+        .keys()
+        .filter_map(|material| carrier(*material, &nuclear_materials, &backpacks))
+        .filter(|operator| operators.contains(*operator))
+        .collect();
+    for operator in signatures.iter() {
+        if !carriers.contains(&operator) {
+            debug!("operator {} no longer carries nuclear material", operator);
+            commands.entity(operator).remove::<RadiationSignature>();
+        }
+    }
+    for &operator in carriers.iter() {
+        let revealed = operators.get(operator).unwrap_or(true);
+        if revealed {
+            continue;
+        }
+        debug!("operator {} carries nuclear material", operator);
+        commands.entity(operator).insert(RadiationSignature);
+        for guard in guards.iter() {
+            commands
+                .entity(guard)
+                .insert(FollowTarget(operator))
+                .insert(FollowBehaviour {
+                    speed: GUARD_SPEED,
+                    keep_distance: GUARD_KEEP_DISTANCE,
+                });
+        }
+    }
+}
+
+/// a member of the contract squad carrying the material into the drop-off secures it
+#[allow(clippy::too_many_arguments)]
+fn drop_off_nuclear_material(
+    mut commands: Commands,
+    materials_in_raid: Option<ResMut<NuclearMaterials>>,
+    nuclear_materials: Query<&Parent, With<NuclearMaterial>>,
+    backpacks: Query<&Parent, With<Inventory>>,
+    operators: Query<(&SquadId, &GlobalTransform), With<Operator>>,
+    drop_offs: Query<(&ContractId, &Volume, &GlobalTransform), With<NuclearDropOff>>,
+    squads: Res<Squads>,
+    mut contracts: ResMut<Contracts>,
+    mut completed: EventWriter<ContractCompleted>,
+) {
+    let Some(mut materials_in_raid) = materials_in_raid else {
+        return;
+    };
+    let dropped_off: Vec<(Entity, ContractId)> = materials_in_raid
+        .map
+        .iter()
+        .filter(|(material, contract_id)| {
+            let Some((squad_id, transform)) = carrier(**material, &nuclear_materials, &backpacks)
+                .and_then(|operator| operators.get(operator).ok())
+            else {
+                return false;
+            };
+            contract_squad(&squads, **contract_id) == Some(squad_id)
+                && drop_offs
+                    .iter()
+                    .any(|(drop_off_contract_id, volume, drop_off)| {
+                        drop_off_contract_id == *contract_id
+                            && volume.contains(drop_off.translation(), transform.translation())
+                    })
+        })
+        .map(|(material, contract_id)| (*material, *contract_id))
+        .collect();
+    for (material, contract_id) in dropped_off {
+        debug!("nuclear material of contract {:?} dropped off", contract_id);
+        materials_in_raid.map.remove(&material);
+        commands.entity(material).despawn_recursive();
+        secure_nuclear_material(&mut contracts, contract_id, &mut completed);
+    }
+}
+
+/// a member of the contract squad exfilling with the material secures it as well
+#[allow(clippy::too_many_arguments)]
+fn exfil_with_nuclear_material(
+    mut exfilled: EventReader<ExfilExitedAO>,
+    materials_in_raid: Option<ResMut<NuclearMaterials>>,
+    nuclear_materials: Query<&Parent, With<NuclearMaterial>>,
+    backpacks: Query<&Parent, With<Inventory>>,
+    operators: Query<&SquadId, With<Operator>>,
+    squads: Res<Squads>,
+    mut contracts: ResMut<Contracts>,
+    mut completed: EventWriter<ContractCompleted>,
+) {
+    let Some(mut materials_in_raid) = materials_in_raid else {
+        return;
+    };
+    for event in exfilled.read() {
+        let Ok(squad_id) = operators.get(event.operator_entity) else {
+            continue;
+        };
+        let exfilled_with: Vec<(Entity, ContractId)> = materials_in_raid
+            .map
+            .iter()
+            .filter(|(material, contract_id)| {
+                carrier(**material, &nuclear_materials, &backpacks) == Some(event.operator_entity)
+                    && contract_squad(&squads, **contract_id) == Some(squad_id)
+            })
+            .map(|(material, contract_id)| (*material, *contract_id))
+            .collect();
+        for (material, contract_id) in exfilled_with {
+            debug!("nuclear material of contract {:?} exfilled", contract_id);
+            materials_in_raid.map.remove(&material);
+            secure_nuclear_material(&mut contracts, contract_id, &mut completed);
+        }
+    }
+}
+
+/// the material of a failed or cancelled contract is just loot, it does not irradiate its carrier
+/// anymore and the guards stop chasing
+fn nuclear_contract_ended(
+    mut commands: Commands,
+    mut failed: EventReader<ContractFailed>,
+    mut cancelled: EventReader<ContractCancelled>,
+    materials_in_raid: Option<ResMut<NuclearMaterials>>,
+    nuclear_materials: Query<(Entity, &ContractId), With<NuclearMaterial>>,
+    guards: Query<(Entity, &ContractId), With<NuclearGuard>>,
+) {
+    let Some(mut materials_in_raid) = materials_in_raid else {
+        return;
+    };
+    let ended = ended_contracts(&mut failed, &mut cancelled);
+    if ended.is_empty() {
+        return;
+    }
+    materials_in_raid
+        .map
+        .retain(|_, contract_id| !ended.contains(contract_id));
+    for (material, contract_id) in nuclear_materials.iter() {
+        if ended.contains(contract_id) {
+            commands.entity(material).remove::<RadiationSource>();
+        }
+    }
+    for (guard, contract_id) in guards.iter() {
+        if ended.contains(contract_id) {
+            debug!("nuclear guard {} stops chasing", guard);
+            commands
+                .entity(guard)
+                .remove::<FollowTarget>()
+                .remove::<FollowBehaviour>();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn bye_secure_nuclear_materials(
+    mut commands: Commands,
+    entities: Query<Entity, Or<(With<NuclearContainer>, With<NuclearGuard>)>>,
+) {
+    debug!("stopping {}", NAME);
+    commands.remove_resource::<NuclearMaterials>();
+    for entity in entities.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// helper functions
+
+/// operator with the material in the backpack, if any
+fn carrier(
+    material: Entity,
+    nuclear_materials: &Query<&Parent, With<NuclearMaterial>>,
+    backpacks: &Query<&Parent, With<Inventory>>,
+) -> Option<Entity> {
+    nuclear_materials
+        .get(material)
+        .ok()
+        .and_then(|backpack| backpacks.get(backpack.get()).ok())
+        .map(|operator| operator.get())
+}
+
+fn secure_nuclear_material(
+    contracts: &mut Contracts,
+    contract_id: ContractId,
+    completed: &mut EventWriter<ContractCompleted>,
+) {
+    let Some(contract) = contracts.map.get_mut(&contract_id) else {
+        return;
+    };
+    if let ContractState::SecureNuclearMaterials(
+        mut state @ SecureNuclearMaterialsState::MaterialsRecovered,
+    ) = contract.contract_state
+    {
+        contract.contract_state = state.next();
+        completed.send(ContractCompleted { contract_id });
+    }
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::contracts::insert_held_contract;
+
+    fn nuclear_app(contract_id: ContractId) -> (App, Entity, Entity) {
+        let mut app = App::new();
+        app.add_event::<ExfilExitedAO>();
+        app.add_event::<ContractCompleted>();
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::SecureNuclearMaterials,
+            ContractState::SecureNuclearMaterials(SecureNuclearMaterialsState::MaterialsRecovered),
+        );
+        let operator = app
+            .world_mut()
+            .spawn((Operator, SquadId(1), GlobalTransform::default()))
+            .id();
+        let backpack = app.world_mut().spawn(Inventory).set_parent(operator).id();
+        let material = app
+            .world_mut()
+            .spawn((
+                Loot,
+                NuclearMaterial,
+                RadiationSource {
+                    intensity: MATERIAL_RADIATION_INTENSITY,
+                },
+                contract_id,
+            ))
+            .set_parent(backpack)
+            .id();
+        let mut materials_in_raid = NuclearMaterials::default();
+        materials_in_raid.map.insert(material, contract_id);
+        app.insert_resource(materials_in_raid);
+        (app, operator, material)
+    }
+
+    #[test]
+    fn should_reveal_carrier() {
+        // given
+        let contract_id = ContractId(61);
+        let (mut app, operator, _) = nuclear_app(contract_id);
+        app.add_systems(Update, reveal_carrier);
+        let guard = app.world_mut().spawn(NuclearGuard).id();
+
+        // when
+        app.update();
+
+        // then
+        assert!(app.world().get::<RadiationSignature>(operator).is_some());
+        assert_eq!(operator, app.world().get::<FollowTarget>(guard).unwrap().0);
+    }
+
+    #[test]
+    fn should_secure_material_when_carrier_exfilled() {
+        // given
+        let contract_id = ContractId(62);
+        let (mut app, operator, _) = nuclear_app(contract_id);
+        app.add_systems(Update, exfil_with_nuclear_material);
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<ExfilExitedAO>>()
+            .send(ExfilExitedAO {
+                operator_entity: operator,
+            });
+        app.update();
+
+        // then
+        assert_eq!(
+            ContractState::SecureNuclearMaterials(SecureNuclearMaterialsState::MaterialsSecured),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        assert!(app.world().resource::<NuclearMaterials>().map.is_empty());
+        let completed_events = app.world().resource::<Events<ContractCompleted>>();
+        let mut completed_reader = completed_events.get_cursor();
+        assert_eq!(
+            vec![&ContractCompleted { contract_id }],
+            completed_reader.read(completed_events).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_secure_material_in_drop_off() {
+        // given
+        let contract_id = ContractId(63);
+        let (mut app, _, material) = nuclear_app(contract_id);
+        app.add_systems(Update, drop_off_nuclear_material);
+        app.world_mut().spawn((
+            NuclearDropOff,
+            contract_id,
+            Volume(Aabb3d::new(Vec3::ZERO, Vec3::new(3.0, 2.0, 3.0))),
+            GlobalTransform::from_xyz(1.0, 0.0, 1.0),
+        ));
+
+        // when
+        app.update();
+
+        // then
+        assert_eq!(
+            ContractState::SecureNuclearMaterials(SecureNuclearMaterialsState::MaterialsSecured),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        assert!(app.world().get::<NuclearMaterial>(material).is_none());
+    }
+
+    #[test]
+    fn should_stop_irradiating_when_contract_failed() {
+        // given
+        let contract_id = ContractId(64);
+        let (mut app, operator, material) = nuclear_app(contract_id);
+        app.add_event::<ContractFailed>();
+        app.add_event::<ContractCancelled>();
+        app.add_systems(Update, (nuclear_contract_ended, reveal_carrier).chain());
+        let guard = app
+            .world_mut()
+            .spawn((NuclearGuard, contract_id, FollowTarget(operator)))
+            .id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<ContractFailed>>()
+            .send(ContractFailed { contract_id });
+        app.update();
+
+        // then
+        assert!(app.world().resource::<NuclearMaterials>().map.is_empty());
+        assert!(app.world().get::<RadiationSource>(material).is_none());
+        assert!(app.world().get::<RadiationSignature>(operator).is_none());
+        assert!(app.world().get::<FollowTarget>(guard).is_none());
+    }
+}
@@ -0,0 +1,299 @@
+use bevy::app::Plugin;
+use bevy::utils::HashMap;
+
+use crate::contracts::{
+    contract_squad, ended_contracts, ContractAccepted, ContractCancelled, ContractCompleted,
+    ContractFailed, ContractGeneralArea, ContractId, ContractState, ContractStateMachine,
+    ContractType, Contracts,
+};
+use crate::exfil::Operator;
+use crate::interaction::{HoldInteractable, Interact, Interactable};
+use crate::squad::{SquadId, Squads};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "signal intelligence";
+/// seconds the interaction key has to be held down to activate an uplink
+const ACTIVATE_UPLINK_SECS: f32 = 5.0;
+
+// Plugin
+/// contract to activate all uplink stations of an area, one general area after the other
+pub struct SignalIntelligencePlugin;
+
+impl Plugin for SignalIntelligencePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Raid), start_signal_intelligence)
+            .add_systems(
+                Update,
+                (set_up_uplinks, activate_uplink, uplinks_contract_ended)
+                    .chain()
+                    .run_if(in_state(AppState::Raid)),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_signal_intelligence);
+    }
+}
+
+// Components
+
+/// radio tower or uplink station of the signal intelligence contract with the same `ContractId`
+#[derive(Component, Debug)]
+pub struct Uplink;
+
+// Resources
+
+/// uplinks still to be activated and the general area revealed per contract
+#[derive(Resource, Default)]
+struct Uplinks {
+    pending: HashMap<Entity, (ContractId, Vec3)>,
+    revealed: HashMap<ContractId, Entity>,
+}
+
+// Events
+
+// Systems
+fn start_signal_intelligence(mut commands: Commands) {
+    debug!("starting {}", NAME);
+    commands.init_resource::<Uplinks>();
+}
+
+/// all uplinks of the contract can be activated, but only the area of one is revealed
+fn set_up_uplinks(
+    mut commands: Commands,
+    mut accepted: EventReader<ContractAccepted>,
+    contracts: Res<Contracts>,
+    uplinks: Option<ResMut<Uplinks>>,
+    stations: Query<(Entity, &ContractId, &GlobalTransform), With<Uplink>>,
+) {
+    let Some(mut uplinks) = uplinks else {
+        return;
+    };
+    for event in accepted.read() {
+        let is_signal_contract = contracts
+            .map
+            .get(&event.contract_id)
+            .is_some_and(|contract| contract.contract_type == ContractType::SignalIntelligence);
+        if !is_signal_contract {
+            continue;
+        }
+        for (station, _, transform) in stations
+            .iter()
+            .filter(|(_, contract_id, _)| **contract_id == event.contract_id)
+        {
+            commands
+                .entity(station)
+                .insert(Interactable)
+                .insert(HoldInteractable(ACTIVATE_UPLINK_SECS));
+            uplinks
+                .pending
+                .insert(station, (event.contract_id, transform.translation()));
+        }
+        debug!(
+            "{} uplinks for contract {:?}",
+            uplinks.pending.len(),
+            event.contract_id
+        );
+        // the first area revealed is the one closest to the center of the map
+        reveal_next_uplink(&mut commands, &mut uplinks, event.contract_id, Vec3::ZERO);
+    }
+}
+
+/// every uplink activated by the contract squad advances the contract until none is left
+fn activate_uplink(
+    mut commands: Commands,
+    mut interactions: EventReader<Interact>,
+    uplinks: Option<ResMut<Uplinks>>,
+    operators: Query<&SquadId, With<Operator>>,
+    squads: Res<Squads>,
+    mut contracts: ResMut<Contracts>,
+    mut completed: EventWriter<ContractCompleted>,
+) {
+    let Some(mut uplinks) = uplinks else {
+        return;
+    };
+    for interaction in interactions.read() {
+        let Some(&(contract_id, position)) = uplinks.pending.get(&interaction.interaction_entity)
+        else {
+            continue;
+        };
+        let Ok(squad_id) = operators.get(interaction.operator_entity) else {
+            continue;
+        };
+        if contract_squad(&squads, contract_id) != Some(squad_id) {
+            debug!("squad {:?} can not use this uplink", squad_id);
+            continue;
+        }
+        let Some(contract) = contracts.map.get_mut(&contract_id) else {
+            continue;
+        };
+        let ContractState::SignalIntelligence(mut state) = contract.contract_state else {
+            continue;
+        };
+        contract.contract_state = state.next();
+        debug!(
+            "uplink {} activated: {:?}",
+            interaction.interaction_entity, contract.contract_state
+        );
+        uplinks.pending.remove(&interaction.interaction_entity);
+        commands
+            .entity(interaction.interaction_entity)
+            .remove::<Interactable>()
+            .remove::<HoldInteractable>();
+        if !reveal_next_uplink(&mut commands, &mut uplinks, contract_id, position) {
+            completed.send(ContractCompleted { contract_id });
+        }
+    }
+}
+
+/// uplinks of a failed or cancelled contract can't be activated anymore, the revealed area is
+/// removed with the contract
+fn uplinks_contract_ended(
+    mut commands: Commands,
+    mut failed: EventReader<ContractFailed>,
+    mut cancelled: EventReader<ContractCancelled>,
+    uplinks: Option<ResMut<Uplinks>>,
+) {
+    let Some(mut uplinks) = uplinks else {
+        return;
+    };
+    let ended = ended_contracts(&mut failed, &mut cancelled);
+    if ended.is_empty() {
+        return;
+    }
+    uplinks.pending.retain(|station, (contract_id, _)| {
+        if !ended.contains(contract_id) {
+            return true;
+        }
+        commands
+            .entity(*station)
+            .remove::<Interactable>()
+            .remove::<HoldInteractable>();
+        false
+    });
+    uplinks
+        .revealed
+        .retain(|contract_id, _| !ended.contains(contract_id));
+}
+
+fn bye_signal_intelligence(mut commands: Commands) {
+    debug!("stopping {}", NAME);
+    commands.remove_resource::<Uplinks>();
+}
+
+// helper functions
+
+/// moves the general area of the contract to the pending uplink closest to the given position,
+/// false if no uplink is left
+fn reveal_next_uplink(
+    commands: &mut Commands,
+    uplinks: &mut Uplinks,
+    contract_id: ContractId,
+    from: Vec3,
+) -> bool {
+    if let Some(area) = uplinks.revealed.remove(&contract_id) {
+        commands.entity(area).despawn_recursive();
+    }
+    let Some(next) = closest_pending_uplink(uplinks, contract_id, from) else {
+        return false;
+    };
+    let area = commands
+        .spawn(Transform::from_translation(next))
+        .insert(Name::new("Uplink General Area"))
+        .insert(ContractGeneralArea::default())
+        .insert(contract_id)
+        .id();
+    uplinks.revealed.insert(contract_id, area);
+    true
+}
+
+fn closest_pending_uplink(uplinks: &Uplinks, contract_id: ContractId, from: Vec3) -> Option<Vec3> {
+    uplinks
+        .pending
+        .values()
+        .filter(|(id, _)| *id == contract_id)
+        .map(|(_, position)| *position)
+        .min_by(|a, b| a.distance(from).total_cmp(&b.distance(from)))
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::contracts::{insert_held_contract, SignalIntelligenceState};
+
+    #[test]
+    fn should_activate_uplinks_in_any_order_and_reveal_next() {
+        // given
+        let mut app = App::new();
+        app.add_event::<Interact>();
+        app.add_event::<ContractCompleted>();
+        app.add_systems(Update, activate_uplink);
+        let contract_id = ContractId(31);
+        insert_held_contract(
+            &mut app,
+            contract_id,
+            ContractType::SignalIntelligence,
+            ContractState::SignalIntelligence(SignalIntelligenceState::Started),
+        );
+        let near = app.world_mut().spawn(Uplink).id();
+        let far = app.world_mut().spawn(Uplink).id();
+        let other = app.world_mut().spawn(Uplink).id();
+        let operator = app.world_mut().spawn((Operator, SquadId(1))).id();
+        app.insert_resource(Uplinks {
+            pending: [
+                (near, (contract_id, Vec3::new(10.0, 0.0, 0.0))),
+                (far, (contract_id, Vec3::new(50.0, 0.0, 0.0))),
+                (other, (contract_id, Vec3::new(-5.0, 0.0, 0.0))),
+            ]
+            .into_iter()
+            .collect(),
+            revealed: HashMap::default(),
+        });
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<Interact>>()
+            .send(Interact {
+                interaction_entity: near,
+                operator_entity: operator,
+            });
+        app.update();
+
+        // then
+        assert_eq!(
+            ContractState::SignalIntelligence(SignalIntelligenceState::UplinksActivated(1)),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        let area = app.world().resource::<Uplinks>().revealed[&contract_id];
+        assert_eq!(
+            Vec3::new(-5.0, 0.0, 0.0),
+            app.world().get::<Transform>(area).unwrap().translation
+        );
+
+        // when
+        for uplink in [far, other] {
+            app.world_mut()
+                .resource_mut::<Events<Interact>>()
+                .send(Interact {
+                    interaction_entity: uplink,
+                    operator_entity: operator,
+                });
+        }
+        app.update();
+
+        // then
+        assert_eq!(
+            ContractState::SignalIntelligence(SignalIntelligenceState::UplinksActivated(3)),
+            app.world().resource::<Contracts>().map[&contract_id].contract_state
+        );
+        assert!(app.world().resource::<Uplinks>().revealed.is_empty());
+        let completed_events = app.world().resource::<Events<ContractCompleted>>();
+        let mut completed_reader = completed_events.get_cursor();
+        assert_eq!(
+            vec![&ContractCompleted { contract_id }],
+            completed_reader.read(completed_events).collect::<Vec<_>>()
+        );
+    }
+}
@@ -10,6 +10,8 @@ use bevy_inspector_egui::prelude::*;
 
 // Constants
 const NAME: &str = "spawn";
+/// distance between neighbouring operators of a formation
+const FORMATION_SPACING: f32 = 2.0;
 
 #[derive(Reflect)]
 pub enum Formation {
@@ -18,6 +20,35 @@ pub enum Formation {
     Line,
 }
 
+impl Formation {
+    /// position of the nth operator relative to the spawn, the spawn faces -z so rows go to +z
+    pub fn slot(&self, index: usize) -> Vec3 {
+        match self {
+            Formation::Line => Vec3::new(line_offset(index), 0.0, 0.0),
+            Formation::Staggered => Vec3::new(
+                line_offset(index),
+                0.0,
+                (index % 2) as f32 * FORMATION_SPACING * 0.5,
+            ),
+            Formation::Triangle => {
+                // row r holds r + 1 operators
+                let mut row = 0;
+                let mut first = 0;
+                while index > first + row {
+                    first += row + 1;
+                    row += 1;
+                }
+                let column = (index - first) as f32 - row as f32 * 0.5;
+                Vec3::new(
+                    column * FORMATION_SPACING,
+                    0.0,
+                    row as f32 * FORMATION_SPACING,
+                )
+            }
+        }
+    }
+}
+
 // Plugin
 pub struct SpawnPlugin;
 
@@ -143,12 +174,30 @@ fn bye_spawn(mut _commands: Commands) {
 
 // helper functions
 
+/// 0, +1, -1, +2, -2, .. times the spacing, so a line stays centered on the spawn
+fn line_offset(index: usize) -> f32 {
+    let side = if index % 2 == 1 { 1.0 } else { -1.0 };
+    side * index.div_ceil(2) as f32 * FORMATION_SPACING
+}
+
 // tests
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use super::*;
 
+    #[test]
+    fn should_place_formation_slots() {
+        assert_eq!(Vec3::ZERO, Formation::Line.slot(0));
+        assert_eq!(Vec3::new(2.0, 0.0, 0.0), Formation::Line.slot(1));
+        assert_eq!(Vec3::new(-2.0, 0.0, 0.0), Formation::Line.slot(2));
+        assert_eq!(Vec3::new(2.0, 0.0, 1.0), Formation::Staggered.slot(1));
+        assert_eq!(Vec3::ZERO, Formation::Triangle.slot(0));
+        assert_eq!(Vec3::new(-1.0, 0.0, 2.0), Formation::Triangle.slot(1));
+        assert_eq!(Vec3::new(1.0, 0.0, 2.0), Formation::Triangle.slot(2));
+        assert_eq!(Vec3::new(-2.0, 0.0, 4.0), Formation::Triangle.slot(3));
+    }
+
     #[test]
     fn should_move_single_operator_to_single_spawn_position() {
         // given
@@ -1,7 +1,9 @@
 use bevy::app::Plugin;
 use bevy::utils::HashMap;
 
-use crate::contracts::{ContractAccepted, ContractId, ContractPhoneInteracted};
+use crate::contracts::{
+    ContractAccepted, ContractCancelled, ContractFailed, ContractId, ContractPhoneInteracted,
+};
 use crate::exfil::Operator;
 use crate::AppState;
 use crate::AppState::Raid;
@@ -25,6 +27,7 @@ impl Plugin for SquadPlugin {
                     operator_added_to_squad,
                     update_squad_system,
                     contract_phone_interacted,
+                    release_contract,
                 )
                     .run_if(in_state(AppState::Raid)),
             )
@@ -115,6 +118,26 @@ fn contract_phone_interacted(
     }
 }
 
+/// a cancelled or failed contract frees the squad to take another contract phone
+fn release_contract(
+    mut cancelled: EventReader<ContractCancelled>,
+    mut failed: EventReader<ContractFailed>,
+    mut squads: ResMut<Squads>,
+) {
+    let released = cancelled
+        .read()
+        .map(|event| event.contract_id)
+        .chain(failed.read().map(|event| event.contract_id));
+    for contract_id in released {
+        for (squad_id, squad) in squads.map.iter_mut() {
+            if squad.current_contract == Some(contract_id) {
+                debug!("squad {:?} released contract {:?}", squad_id, contract_id);
+                squad.current_contract = None;
+            }
+        }
+    }
+}
+
 fn bye_squad_system(mut _commands: Commands) {
     debug!("stopping {}", NAME);
 }
@@ -125,24 +148,47 @@ fn bye_squad_system(mut _commands: Commands) {
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
-    //use super::*;
+    use super::*;
 
-    /*
     #[test]
-    fn should_test_something() {
+    fn should_release_cancelled_and_failed_contracts() {
         // given
-        //let mut _app = App::new();
+        let mut app = App::new();
+        app.add_event::<ContractCancelled>();
+        app.add_event::<ContractFailed>();
+        app.add_systems(Update, release_contract);
+        let mut squads = Squads::default();
+        for (squad_id, contract_id) in [(1, 1), (2, 2), (3, 3)] {
+            squads.map.insert(
+                SquadId(squad_id),
+                Squad {
+                    current_contract: Some(ContractId(contract_id)),
+                    ..default()
+                },
+            );
+        }
+        app.insert_resource(squads);
 
         // when
-        //app.add_event::<HealthDamageReceived>();
-        //app.add_systems(Update, damage_received_listener);
-        //let entity = app.borrow_mut().world.spawn(Health(100)).id();
-        //app.borrow_mut().world.resource_mut::<Events<HealthDamageReceived>>().send(HealthDamageReceived { entity, damage: 10 });
-        //app.update();
+        app.world_mut()
+            .resource_mut::<Events<ContractCancelled>>()
+            .send(ContractCancelled {
+                contract_id: ContractId(1),
+            });
+        app.world_mut()
+            .resource_mut::<Events<ContractFailed>>()
+            .send(ContractFailed {
+                contract_id: ContractId(2),
+            });
+        app.update();
 
         // then
-        //assert!(app.world.get::<Health>(entity).is_some());
-        //assert_eq!(app.world.get::<Health>(entity).unwrap().0, 90);
+        let squads = app.world().resource::<Squads>();
+        assert_eq!(None, squads.map[&SquadId(1)].current_contract);
+        assert_eq!(None, squads.map[&SquadId(2)].current_contract);
+        assert_eq!(
+            Some(ContractId(3)),
+            squads.map[&SquadId(3)].current_contract
+        );
     }
-    */
 }
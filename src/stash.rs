@@ -0,0 +1,134 @@
+use bevy::app::Plugin;
+use bevy_inspector_egui::prelude::*;
+
+use crate::raid_summary::{ExtractedItem, RaidOutcome, RaidSummaries};
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "stash";
+
+// Plugin
+/// items and cash that made it out of a raid, kept in between raids
+pub struct StashPlugin;
+
+impl Plugin for StashPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Stash>()
+            .init_resource::<Bank>()
+            .register_type::<Stash>()
+            .register_type::<Bank>()
+            .add_event::<RaidSummaryConfirmed>()
+            .add_systems(Update, raid_summary_confirmed);
+    }
+}
+
+// Components
+
+// Resources
+
+#[derive(Resource, Default, Reflect, InspectorOptions)]
+#[reflect(Resource, InspectorOptions)]
+pub struct Stash {
+    pub items: Vec<ExtractedItem>,
+}
+
+/// cash extracted from raids
+#[derive(Resource, Default, Reflect, InspectorOptions)]
+#[reflect(Resource, InspectorOptions)]
+pub struct Bank {
+    pub balance: u32,
+}
+
+// Events
+
+/// the player confirmed the raid summary, what was extracted goes to the stash and the bank
+#[derive(Event, Debug, PartialEq)]
+pub struct RaidSummaryConfirmed;
+
+// Systems
+
+/// only an exfilled player keeps the extracted items and cash, taken out of the summary so
+/// they are only transferred once
+fn raid_summary_confirmed(
+    mut confirmed: EventReader<RaidSummaryConfirmed>,
+    summaries: Option<ResMut<RaidSummaries>>,
+    mut stash: ResMut<Stash>,
+    mut bank: ResMut<Bank>,
+) {
+    if confirmed.read().count() == 0 {
+        return;
+    }
+    let Some(mut summaries) = summaries else {
+        return;
+    };
+    let Some(player) = summaries.player else {
+        return;
+    };
+    let Some(summary) = summaries.map.get_mut(&player) else {
+        return;
+    };
+    if summary.outcome != RaidOutcome::Exfilled {
+        debug!("{}: nothing extracted, outcome {:?}", NAME, summary.outcome);
+        return;
+    }
+    let cash = std::mem::take(&mut summary.extracted_cash);
+    bank.balance = bank.balance.saturating_add(cash);
+    stash.items.append(&mut summary.extracted_items);
+    debug!(
+        "{}: {} items stashed, bank balance {}",
+        NAME,
+        stash.items.len(),
+        bank.balance
+    );
+}
+
+// helper functions
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::raid_summary::RaidSummary;
+
+    #[test]
+    fn should_transfer_extracted_items_and_cash_once() {
+        // given
+        let mut app = App::new();
+        app.add_event::<RaidSummaryConfirmed>();
+        app.init_resource::<Stash>();
+        app.insert_resource(Bank { balance: 100 });
+        app.add_systems(Update, raid_summary_confirmed);
+        let player = app.world_mut().spawn_empty().id();
+        let item = ExtractedItem {
+            name: String::from("Gold Bar"),
+            value: 50_000,
+        };
+        let mut summaries = RaidSummaries {
+            player: Some(player),
+            ..default()
+        };
+        summaries.map.insert(
+            player,
+            RaidSummary {
+                outcome: RaidOutcome::Exfilled,
+                extracted_items: vec![item.clone()],
+                extracted_cash: 1_500,
+                ..default()
+            },
+        );
+        app.insert_resource(summaries);
+
+        // when
+        for _ in 0..2 {
+            app.world_mut()
+                .resource_mut::<Events<RaidSummaryConfirmed>>()
+                .send(RaidSummaryConfirmed);
+            app.update();
+        }
+
+        // then
+        assert_eq!(vec![item], app.world().resource::<Stash>().items);
+        assert_eq!(1_600, app.world().resource::<Bank>().balance);
+    }
+}
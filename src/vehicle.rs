@@ -0,0 +1,257 @@
+use bevy::app::Plugin;
+
+use crate::exfil::Operator;
+use crate::first_person_controller::PlayerControlled;
+use crate::interaction::Interact;
+use crate::raid::{PlayerControlsEnabled, RaidState};
+use crate::AppState;
+use crate::AppState::Raid;
+use bevy::prelude::*;
+
+// Constants
+const NAME: &str = "vehicle";
+/// where the driver sits, relative to the vehicle
+const SEAT_OFFSET: Vec3 = Vec3::new(-0.5, 1.5, 0.5);
+/// where the driver gets out, relative to the vehicle
+const EXIT_OFFSET: Vec3 = Vec3::new(-2.5, 0.0, 0.0);
+/// vehicles roll to a stop without throttle, in meters per second squared
+const ROLLING_DECELERATION: f32 = 2.0;
+
+// Plugin
+/// vehicles operators can get into and drive
+pub struct VehiclePlugin;
+
+impl Plugin for VehiclePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<VehicleEntered>()
+            .add_event::<VehicleExited>()
+            .add_systems(OnEnter(Raid), start_vehicle)
+            .add_systems(
+                Update,
+                (enter_vehicle, exit_vehicle, drive_vehicle).chain().run_if(
+                    in_state(AppState::Raid)
+                        .and(in_state(RaidState::Raid))
+                        .and(resource_exists::<PlayerControlsEnabled>),
+                ),
+            )
+            .add_systems(OnExit(AppState::Raid), bye_vehicle);
+    }
+}
+
+// Components
+
+#[derive(Component, Debug, Clone)]
+pub struct Vehicle {
+    /// current speed in meters per second, negative when backing up
+    pub speed: f32,
+    pub max_speed: f32,
+    /// meters per second squared
+    pub acceleration: f32,
+    /// radians per second at full speed
+    pub turn_rate: f32,
+}
+
+impl Default for Vehicle {
+    fn default() -> Self {
+        Vehicle {
+            speed: 0.0,
+            max_speed: 12.0,
+            acceleration: 4.0,
+            turn_rate: 1.0,
+        }
+    }
+}
+
+/// operator currently driving the vehicle
+#[derive(Component, Debug)]
+pub struct Driver {
+    pub vehicle: Entity,
+}
+
+/// vehicle currently driven by the operator
+#[derive(Component, Debug)]
+pub struct DrivenBy(pub Entity);
+
+// Resources
+
+// Events
+#[derive(Event, Debug, PartialEq)]
+pub struct VehicleEntered {
+    pub vehicle: Entity,
+    pub driver: Entity,
+}
+
+#[derive(Event, Debug, PartialEq)]
+pub struct VehicleExited {
+    pub vehicle: Entity,
+    pub driver: Entity,
+}
+
+// Systems
+fn start_vehicle(mut _commands: Commands) {
+    debug!("starting {}", NAME);
+}
+
+/// interacting with a free vehicle puts the operator behind the wheel
+fn enter_vehicle(
+    mut commands: Commands,
+    mut interactions: EventReader<Interact>,
+    vehicles: Query<(), (With<Vehicle>, Without<DrivenBy>)>,
+    drivers: Query<(), (With<Operator>, Without<Driver>)>,
+    mut entered: EventWriter<VehicleEntered>,
+) {
+    for interaction in interactions.read() {
+        let vehicle = interaction.interaction_entity;
+        let driver = interaction.operator_entity;
+        if !vehicles.contains(vehicle) || !drivers.contains(driver) {
+            continue;
+        }
+        debug!("{} drives {}", driver, vehicle);
+        commands.entity(vehicle).insert(DrivenBy(driver));
+        commands.entity(driver).insert(Driver { vehicle });
+        entered.send(VehicleEntered { vehicle, driver });
+    }
+}
+
+fn exit_vehicle(
+    mut commands: Commands,
+    key_input: Res<ButtonInput<KeyCode>>,
+    drivers: Query<(Entity, &Driver), With<PlayerControlled>>,
+    mut vehicles: Query<(&mut Vehicle, &Transform), Without<Driver>>,
+    mut transforms: Query<&mut Transform, With<Driver>>,
+    mut exited: EventWriter<VehicleExited>,
+) {
+    if !key_input.just_released(KeyCode::KeyE) {
+        return;
+    }
+    for (driver, seat) in drivers.iter() {
+        debug!("{} gets out of {}", driver, seat.vehicle);
+        if let Ok((mut vehicle, vehicle_transform)) = vehicles.get_mut(seat.vehicle) {
+            vehicle.speed = 0.0;
+            if let Ok(mut transform) = transforms.get_mut(driver) {
+                transform.translation = vehicle_transform.transform_point(EXIT_OFFSET);
+            }
+        }
+        commands.entity(seat.vehicle).remove::<DrivenBy>();
+        commands.entity(driver).remove::<Driver>();
+        exited.send(VehicleExited {
+            vehicle: seat.vehicle,
+            driver,
+        });
+    }
+}
+
+/// W and S for throttle and brake, A and D to steer, the driver moves along on the seat
+fn drive_vehicle(
+    time: Res<Time>,
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut vehicles: Query<(&mut Vehicle, &mut Transform, &DrivenBy), Without<Driver>>,
+    mut seats: Query<&mut Transform, (With<Driver>, With<PlayerControlled>)>,
+) {
+    let throttle = axis(&key_input, KeyCode::KeyW, KeyCode::KeyS);
+    let steering = axis(&key_input, KeyCode::KeyA, KeyCode::KeyD);
+    for (mut vehicle, mut transform, driven_by) in vehicles.iter_mut() {
+        let Ok(mut seat_transform) = seats.get_mut(driven_by.0) else {
+            continue;
+        };
+        let (speed, yaw) = drive_step(&vehicle, throttle, steering, time.delta_secs());
+        vehicle.speed = speed;
+        transform.rotate_y(yaw);
+        let forward = *transform.forward();
+        transform.translation += forward * speed * time.delta_secs();
+        seat_transform.translation = transform.transform_point(SEAT_OFFSET);
+    }
+}
+
+fn bye_vehicle(mut _commands: Commands) {
+    debug!("stopping {}", NAME);
+}
+
+// helper functions
+
+fn axis(key_input: &ButtonInput<KeyCode>, positive: KeyCode, negative: KeyCode) -> f32 {
+    match (key_input.pressed(positive), key_input.pressed(negative)) {
+        (true, false) => 1.0,
+        (false, true) => -1.0,
+        _ => 0.0,
+    }
+}
+
+/// new speed and the yaw to turn by, vehicles only turn while moving
+fn drive_step(vehicle: &Vehicle, throttle: f32, steering: f32, delta_secs: f32) -> (f32, f32) {
+    let speed = if throttle != 0.0 {
+        vehicle.speed + throttle * vehicle.acceleration * delta_secs
+    } else {
+        let slowdown = (ROLLING_DECELERATION * delta_secs).min(vehicle.speed.abs());
+        vehicle.speed - vehicle.speed.signum() * slowdown
+    }
+    .clamp(-vehicle.max_speed / 2.0, vehicle.max_speed);
+    let yaw = steering * vehicle.turn_rate * delta_secs * (speed / vehicle.max_speed);
+    (speed, yaw)
+}
+
+// tests
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn should_accelerate_roll_out_and_turn_only_while_moving() {
+        // given
+        let vehicle = Vehicle {
+            speed: 0.0,
+            max_speed: 10.0,
+            acceleration: 4.0,
+            turn_rate: 1.0,
+        };
+        let rolling = Vehicle {
+            speed: 1.0,
+            ..vehicle.clone()
+        };
+        let flat_out = Vehicle {
+            speed: 10.0,
+            ..vehicle.clone()
+        };
+
+        // then
+        assert_eq!((0.0, 0.0), drive_step(&vehicle, 0.0, 1.0, 0.5));
+        assert_eq!((2.0, 0.1), drive_step(&vehicle, 1.0, 1.0, 0.5));
+        assert_eq!((0.0, 0.0), drive_step(&rolling, 0.0, 0.0, 0.5));
+        assert_eq!((10.0, -0.5), drive_step(&flat_out, 1.0, -1.0, 0.5));
+        assert_eq!(-2.0, drive_step(&vehicle, -1.0, 0.0, 0.5).0);
+    }
+
+    #[test]
+    fn should_enter_free_vehicle_only() {
+        // given
+        let mut app = App::new();
+        app.add_event::<Interact>();
+        app.add_event::<VehicleEntered>();
+        app.add_systems(Update, enter_vehicle);
+        let vehicle = app.world_mut().spawn(Vehicle::default()).id();
+        let driver = app.world_mut().spawn(Operator).id();
+        let passenger = app.world_mut().spawn(Operator).id();
+
+        // when
+        app.world_mut()
+            .resource_mut::<Events<Interact>>()
+            .send(Interact {
+                interaction_entity: vehicle,
+                operator_entity: driver,
+            });
+        app.update();
+        app.world_mut()
+            .resource_mut::<Events<Interact>>()
+            .send(Interact {
+                interaction_entity: vehicle,
+                operator_entity: passenger,
+            });
+        app.update();
+
+        // then
+        assert_eq!(driver, app.world().get::<DrivenBy>(vehicle).unwrap().0);
+        assert_eq!(vehicle, app.world().get::<Driver>(driver).unwrap().vehicle);
+        assert!(app.world().get::<Driver>(passenger).is_none());
+    }
+}